@@ -0,0 +1,102 @@
+// Tokenizes a large, mostly text page a few times and prints the throughput. Run with `cargo bench`,
+// this is timed by hand instead of using a framework. `cargo bench --no-default-features` runs it
+// without the text-fast-path feature, with the text states going through the input one character
+// at a time, to see what scanning runs of text in one go gains.
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use html_parser::{BorrowedTokenizer, RawKind, Token, TokenType, Tokenizer};
+
+const ITERATIONS: u32 = 10;
+
+const ENGLISH: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor \
+    incididunt ut labore et dolore magna aliqua. Ut enim ad minim veniam, quis nostrud \
+    exercitation ullamco laboris nisi ut aliquip ex ea commodo consequat. Duis aute irure \
+    dolor in reprehenderit in voluptate velit esse cillum dolore eu fugiat nulla pariatur.\n";
+
+// non-ASCII text, with no-break spaces and quotation marks, which share their lead byte with the
+// C1 controls.
+const FRENCH_AND_JAPANESE: &str = "Le cœur a ses raisons que la raison ne connaît point\u{A0}: on le \
+    sait en mille choses. «\u{A0}Qu’est-ce que l’été\u{A0}?\u{A0}» demanda-t-il, à peine réveillé. \
+    吾輩は猫である。名前はまだ無い。どこで生れたかとんと見当がつかぬ。何でも薄暗いじめじめした\
+    所でニャーニャー泣いていた事だけは記憶している。\n";
+
+// roughly what an article page looks like: long paragraphs with the odd link, entity and inline
+// element, plus some script and style.
+fn fixture(paragraph: &str) -> String {
+    let mut html = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<title>Fixture</title>\n");
+    html.push_str("<style>\nbody { margin: 0 auto; max-width: 40em; }\np { line-height: 1.5; }\n</style>\n");
+    html.push_str("</head>\n<body>\n");
+    for section in 0..2000 {
+        html.push_str(&format!("<h2 id=\"section-{section}\">Section {section}</h2>\n"));
+        for _ in 0..4 {
+            html.push_str("<p>");
+            html.push_str(paragraph);
+            html.push_str(paragraph);
+            html.push_str("Read <a href=\"/more?page=1&amp;sort=asc\">more</a> &mdash; or <em>don't</em>.</p>\n");
+        }
+        if section % 100 == 0 {
+            html.push_str("<script>\nfor (let i = 0; i < 10; i++) { console.log(i < 5 ? 'low' : 'high'); }\n</script>\n");
+        }
+    }
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+fn report(name: &str, input: &str, tokens: usize, elapsed: Duration) {
+    let per_iteration = elapsed / ITERATIONS;
+    let megabytes = input.len() as f64 / (1024.0 * 1024.0);
+    println!(
+        "{name:<10} {tokens:>8} tokens {per_iteration:>10.2?} per run {:>8.1} MB/s",
+        megabytes / per_iteration.as_secs_f64()
+    );
+}
+
+fn bench_owned(input: &str) {
+    let mut tokens = 0;
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        let mut tokenizer = Tokenizer::new(input);
+        tokens = 0;
+        while let Some(token) = tokenizer.next() {
+            if let TokenType::StartTag { tag_name, .. } = &token
+                && let Some(kind) = RawKind::for_tag_name(tag_name)
+            {
+                tokenizer.set_state(kind.state());
+            }
+            black_box(&token);
+            tokens += 1;
+        }
+    }
+    report("owned", input, tokens, start.elapsed());
+}
+
+fn bench_borrowed(input: &str) {
+    let mut tokens = 0;
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        let mut tokenizer = BorrowedTokenizer::new(input);
+        tokens = 0;
+        while let Some(token) = tokenizer.next() {
+            if let Token::StartTag { tag_name, .. } = &token
+                && let Some(kind) = RawKind::for_tag_name(tag_name)
+            {
+                tokenizer.set_state(kind.state());
+            }
+            black_box(&token);
+            tokens += 1;
+        }
+    }
+    report("borrowed", input, tokens, start.elapsed());
+}
+
+fn main() {
+    let fast_path = if cfg!(feature = "text-fast-path") { "on" } else { "off" };
+    println!("text fast path {fast_path}");
+    for (name, paragraph) in [("english", ENGLISH), ("french and japanese", FRENCH_AND_JAPANESE)] {
+        let input = fixture(paragraph);
+        println!("{name} fixture: {:.1} MB", input.len() as f64 / (1024.0 * 1024.0));
+        bench_owned(&input);
+        bench_borrowed(&input);
+    }
+}
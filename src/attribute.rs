@@ -0,0 +1,311 @@
+use std::fmt;
+
+use crate::local_name::LocalName;
+
+// the namespaces an HTML parser ever puts elements or attributes in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Namespace {
+    Html,
+    MathMl,
+    Svg,
+    XLink,
+    Xml,
+    XmlNs,
+}
+
+impl Namespace {
+    pub fn url(self) -> &'static str {
+        match self {
+            Namespace::Html => "http://www.w3.org/1999/xhtml",
+            Namespace::MathMl => "http://www.w3.org/1998/Math/MathML",
+            Namespace::Svg => "http://www.w3.org/2000/svg",
+            Namespace::XLink => "http://www.w3.org/1999/xlink",
+            Namespace::Xml => "http://www.w3.org/XML/1998/namespace",
+            Namespace::XmlNs => "http://www.w3.org/2000/xmlns/",
+        }
+    }
+}
+
+// The name of an attribute. The tokenizer only ever produces names without a prefix or a
+// namespace, those are given to a handful of attributes on SVG and MathML elements by the tree
+// builder, see TokenType::adjust_foreign_attributes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QualName {
+    pub prefix: Option<String>,
+    pub namespace: Option<Namespace>,
+    pub local: LocalName,
+}
+
+impl QualName {
+    pub fn new(local: LocalName) -> Self {
+        QualName {
+            prefix: None,
+            namespace: None,
+            local,
+        }
+    }
+
+    // whether name is this name written out the way it appeared in the markup, "xlink:href" for
+    // the href attribute in the XLink namespace.
+    pub fn is(&self, name: &str) -> bool {
+        match &self.prefix {
+            Some(prefix) => name
+                .strip_prefix(prefix.as_str())
+                .and_then(|rest| rest.strip_prefix(':'))
+                .is_some_and(|local| self.local == local),
+            None => self.local == name,
+        }
+    }
+}
+
+impl fmt::Display for QualName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(prefix) = &self.prefix {
+            write!(f, "{}:", prefix)?;
+        }
+        f.write_str(&self.local)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attribute {
+    pub name: QualName,
+    pub value: String,
+}
+
+impl Attribute {
+    // an attribute the way the tokenizer makes them, in no namespace.
+    pub fn new(name: LocalName, value: String) -> Self {
+        Attribute {
+            name: QualName::new(name),
+            value,
+        }
+    }
+
+    // "adjust SVG attributes", the tokenizer lowercases attribute names but SVG wants some of
+    // them in camel case.
+    pub(crate) fn adjust_svg(&mut self) {
+        if self.name.namespace.is_none()
+            && let Ok(index) = SVG_ATTRIBUTES.binary_search_by_key(&self.name.local.as_str(), |(from, _)| from)
+        {
+            self.name.local = LocalName::new(SVG_ATTRIBUTES[index].1);
+        }
+    }
+
+    // "adjust MathML attributes", which is only the one.
+    pub(crate) fn adjust_mathml(&mut self) {
+        if self.name.namespace.is_none() && self.name.local == LocalName::DEFINITIONURL {
+            self.name.local = LocalName::DEFINITION_URL;
+        }
+    }
+
+    // "adjust foreign attributes", which puts xlink:, xml: and xmlns attributes in their
+    // namespace.
+    pub(crate) fn adjust_foreign(&mut self) {
+        if self.name.namespace.is_some() {
+            return;
+        }
+        if let Some(&(_, prefix, local, namespace)) =
+            FOREIGN_ATTRIBUTES.iter().find(|(name, ..)| self.name.local == *name)
+        {
+            self.name = QualName {
+                prefix: prefix.map(str::to_string),
+                namespace: Some(namespace),
+                local: LocalName::new(local),
+            };
+        }
+    }
+}
+
+// sorted by the lowercase name, for the binary search.
+static SVG_ATTRIBUTES: [(&str, &str); 58] = [
+    ("attributename", "attributeName"),
+    ("attributetype", "attributeType"),
+    ("basefrequency", "baseFrequency"),
+    ("baseprofile", "baseProfile"),
+    ("calcmode", "calcMode"),
+    ("clippathunits", "clipPathUnits"),
+    ("diffuseconstant", "diffuseConstant"),
+    ("edgemode", "edgeMode"),
+    ("filterunits", "filterUnits"),
+    ("glyphref", "glyphRef"),
+    ("gradienttransform", "gradientTransform"),
+    ("gradientunits", "gradientUnits"),
+    ("kernelmatrix", "kernelMatrix"),
+    ("kernelunitlength", "kernelUnitLength"),
+    ("keypoints", "keyPoints"),
+    ("keysplines", "keySplines"),
+    ("keytimes", "keyTimes"),
+    ("lengthadjust", "lengthAdjust"),
+    ("limitingconeangle", "limitingConeAngle"),
+    ("markerheight", "markerHeight"),
+    ("markerunits", "markerUnits"),
+    ("markerwidth", "markerWidth"),
+    ("maskcontentunits", "maskContentUnits"),
+    ("maskunits", "maskUnits"),
+    ("numoctaves", "numOctaves"),
+    ("pathlength", "pathLength"),
+    ("patterncontentunits", "patternContentUnits"),
+    ("patterntransform", "patternTransform"),
+    ("patternunits", "patternUnits"),
+    ("pointsatx", "pointsAtX"),
+    ("pointsaty", "pointsAtY"),
+    ("pointsatz", "pointsAtZ"),
+    ("preservealpha", "preserveAlpha"),
+    ("preserveaspectratio", "preserveAspectRatio"),
+    ("primitiveunits", "primitiveUnits"),
+    ("refx", "refX"),
+    ("refy", "refY"),
+    ("repeatcount", "repeatCount"),
+    ("repeatdur", "repeatDur"),
+    ("requiredextensions", "requiredExtensions"),
+    ("requiredfeatures", "requiredFeatures"),
+    ("specularconstant", "specularConstant"),
+    ("specularexponent", "specularExponent"),
+    ("spreadmethod", "spreadMethod"),
+    ("startoffset", "startOffset"),
+    ("stddeviation", "stdDeviation"),
+    ("stitchtiles", "stitchTiles"),
+    ("surfacescale", "surfaceScale"),
+    ("systemlanguage", "systemLanguage"),
+    ("tablevalues", "tableValues"),
+    ("targetx", "targetX"),
+    ("targety", "targetY"),
+    ("textlength", "textLength"),
+    ("viewbox", "viewBox"),
+    ("viewtarget", "viewTarget"),
+    ("xchannelselector", "xChannelSelector"),
+    ("ychannelselector", "yChannelSelector"),
+    ("zoomandpan", "zoomAndPan"),
+];
+
+// attribute name, prefix, local name, namespace.
+static FOREIGN_ATTRIBUTES: [(&str, Option<&str>, &str, Namespace); 11] = [
+    ("xlink:actuate", Some("xlink"), "actuate", Namespace::XLink),
+    ("xlink:arcrole", Some("xlink"), "arcrole", Namespace::XLink),
+    ("xlink:href", Some("xlink"), "href", Namespace::XLink),
+    ("xlink:role", Some("xlink"), "role", Namespace::XLink),
+    ("xlink:show", Some("xlink"), "show", Namespace::XLink),
+    ("xlink:title", Some("xlink"), "title", Namespace::XLink),
+    ("xlink:type", Some("xlink"), "type", Namespace::XLink),
+    ("xml:lang", Some("xml"), "lang", Namespace::Xml),
+    ("xml:space", Some("xml"), "space", Namespace::Xml),
+    ("xmlns", None, "xmlns", Namespace::XmlNs),
+    ("xmlns:xlink", Some("xmlns"), "xlink", Namespace::XmlNs),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TokenType, Tokenizer};
+
+    fn named(name: &str) -> Attribute {
+        Attribute::new(LocalName::new(name), String::new())
+    }
+
+    // the attributes of the first tag in input, after adjust.
+    fn adjusted(input: &str, adjust: fn(&mut TokenType)) -> Vec<(Option<String>, Option<Namespace>, String)> {
+        let mut token = Tokenizer::new(input).next().unwrap();
+        adjust(&mut token);
+        let TokenType::StartTag { attributes, .. } = token else {
+            panic!("not a start tag: {:?}", token);
+        };
+        attributes
+            .into_iter()
+            .map(|attribute| (attribute.name.prefix, attribute.name.namespace, attribute.name.local.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn svg_attributes() {
+        // the table in "adjust SVG attributes", in the order of the spec.
+        let spec = [
+            "attributeName", "attributeType", "baseFrequency", "baseProfile", "calcMode", "clipPathUnits",
+            "diffuseConstant", "edgeMode", "filterUnits", "glyphRef", "gradientTransform", "gradientUnits",
+            "kernelMatrix", "kernelUnitLength", "keyPoints", "keySplines", "keyTimes", "lengthAdjust",
+            "limitingConeAngle", "markerHeight", "markerUnits", "markerWidth", "maskContentUnits", "maskUnits",
+            "numOctaves", "pathLength", "patternContentUnits", "patternTransform", "patternUnits", "pointsAtX",
+            "pointsAtY", "pointsAtZ", "preserveAlpha", "preserveAspectRatio", "primitiveUnits", "refX", "refY",
+            "repeatCount", "repeatDur", "requiredExtensions", "requiredFeatures", "specularConstant",
+            "specularExponent", "spreadMethod", "startOffset", "stdDeviation", "stitchTiles", "surfaceScale",
+            "systemLanguage", "tableValues", "targetX", "targetY", "textLength", "viewBox", "viewTarget",
+            "xChannelSelector", "yChannelSelector", "zoomAndPan",
+        ];
+        assert_eq!(SVG_ATTRIBUTES.len(), spec.len());
+        // sorted, for the binary search.
+        assert!(SVG_ATTRIBUTES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for name in spec {
+            let mut attribute = named(&name.to_ascii_lowercase());
+            attribute.adjust_svg();
+            assert_eq!(attribute.name, QualName::new(LocalName::new(name)));
+        }
+
+        for name in ["viewBox", "class", "xlink:href", "definitionurl"] {
+            let mut attribute = named(name);
+            attribute.adjust_svg();
+            assert_eq!(attribute.name.local, name);
+        }
+    }
+
+    #[test]
+    fn mathml_attributes() {
+        let mut attribute = named("definitionurl");
+        attribute.adjust_mathml();
+        assert_eq!(attribute.name, QualName::new(LocalName::DEFINITION_URL));
+        assert_eq!(attribute.name.local, "definitionURL");
+
+        for name in ["definitionURL", "viewbox", "xlink:href"] {
+            let mut attribute = named(name);
+            attribute.adjust_mathml();
+            assert_eq!(attribute.name.local, name);
+        }
+    }
+
+    #[test]
+    fn foreign_attributes() {
+        for &(name, prefix, local, namespace) in &FOREIGN_ATTRIBUTES {
+            let mut attribute = named(name);
+            attribute.adjust_foreign();
+            assert_eq!(attribute.name.prefix.as_deref(), prefix);
+            assert_eq!(attribute.name.namespace, Some(namespace));
+            assert_eq!(attribute.name.local, local);
+            assert!(attribute.name.is(name));
+            assert_eq!(attribute.name.to_string(), name);
+
+            // adjusting twice changes nothing.
+            let adjusted = attribute.clone();
+            attribute.adjust_foreign();
+            assert_eq!(attribute, adjusted);
+        }
+
+        for name in ["xlink:foo", "xml:base", "xmlns:foo", "href", "xlink"] {
+            let mut attribute = named(name);
+            attribute.adjust_foreign();
+            assert_eq!(attribute.name, QualName::new(LocalName::new(name)));
+        }
+    }
+
+    #[test]
+    fn adjusting_tokens() {
+        let input = "<svg viewbox=1 definitionurl=2 xlink:href=3 XML:LANG=4 class=5>";
+        let none = |local: &str| (None, None, local.to_string());
+        assert_eq!(
+            adjusted(input, TokenType::adjust_svg_attributes),
+            [none("viewBox"), none("definitionurl"), none("xlink:href"), none("xml:lang"), none("class")]
+        );
+        assert_eq!(
+            adjusted(input, TokenType::adjust_mathml_attributes),
+            [none("viewbox"), none("definitionURL"), none("xlink:href"), none("xml:lang"), none("class")]
+        );
+        assert_eq!(
+            adjusted(input, TokenType::adjust_foreign_attributes),
+            [
+                none("viewbox"),
+                none("definitionurl"),
+                (Some("xlink".to_string()), Some(Namespace::XLink), "href".to_string()),
+                (Some("xml".to_string()), Some(Namespace::Xml), "lang".to_string()),
+                none("class"),
+            ]
+        );
+    }
+}
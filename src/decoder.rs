@@ -0,0 +1,873 @@
+use crate::encoding::Encoding;
+use crate::encoding_indexes::*;
+
+const REPLACEMENT: char = '\u{FFFD}';
+
+// Turns bytes in one of the encodings into text the way the decoders of the Encoding Standard do,
+// with a U+FFFD for every byte sequence that is not a character. The bytes can come in chunks
+// which split characters anywhere. Like the standard's "decode", a byte order mark at the start
+// overrides the encoding and is left out of the text.
+pub struct Decoder {
+    encoding: Encoding,
+    handler: Handler,
+    // the first bytes, for as long as they might still turn out to be a byte order mark.
+    bom: Option<Vec<u8>>,
+}
+
+impl Decoder {
+    pub(crate) fn new(encoding: Encoding) -> Self {
+        Decoder {
+            encoding,
+            handler: Handler::new(encoding),
+            bom: Some(Vec::new()),
+        }
+    }
+
+    // the encoding the bytes are decoded from, which is only final once the byte order mark has
+    // been looked for.
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    // whether any bytes have been decoded, or held back to see if they are a byte order mark.
+    pub(crate) fn has_started(&self) -> bool {
+        self.bom.as_ref().is_none_or(|start| !start.is_empty())
+    }
+
+    // appends the text of bytes to out, a character split off at the end is finished by the next
+    // call.
+    pub fn decode(&mut self, bytes: &[u8], out: &mut String) {
+        self.decode_bytes(bytes, false, out);
+    }
+
+    // the end of the input, a character that was left unfinished comes out as U+FFFD.
+    pub fn finish(&mut self, out: &mut String) {
+        self.decode_bytes(&[], true, out);
+        loop {
+            let restored = self.handler.handle(None, out);
+            if restored.is_empty() {
+                break;
+            }
+            self.replay(restored, out);
+        }
+    }
+
+    fn decode_bytes(&mut self, mut bytes: &[u8], last: bool, out: &mut String) {
+        if let Some(mut start) = self.bom.take() {
+            let taken = bytes.len().min(3 - start.len());
+            start.extend_from_slice(&bytes[..taken]);
+            bytes = &bytes[taken..];
+            let might_be_bom = [&b"\xEF\xBB\xBF"[..], b"\xFE\xFF", b"\xFF\xFE"]
+                .iter()
+                .any(|bom| bom.len() > start.len() && bom.starts_with(&start));
+            if might_be_bom && !last {
+                self.bom = Some(start);
+                return;
+            }
+
+            let rest = match Encoding::for_bom(&start) {
+                Some(encoding) => {
+                    self.encoding = encoding;
+                    self.handler = Handler::new(encoding);
+                    let bom_len = if encoding == Encoding::Utf8 { 3 } else { 2 };
+                    &start[bom_len..]
+                }
+                None => &start[..],
+            };
+            self.run(rest, out);
+        }
+        self.run(bytes, out);
+    }
+
+    fn run(&mut self, mut bytes: &[u8], out: &mut String) {
+        while let Some(&byte) = bytes.first() {
+            // valid UTF-8 and runs of ASCII can be copied in one go.
+            if let Handler::Utf8 { needed: 0, .. } = self.handler {
+                match std::str::from_utf8(bytes) {
+                    Ok(text) => {
+                        out.push_str(text);
+                        return;
+                    }
+                    Err(error) => {
+                        let (valid, rest) = bytes.split_at(error.valid_up_to());
+                        out.push_str(std::str::from_utf8(valid).unwrap_or_default());
+                        bytes = rest;
+                        // a character cut off at the end is left to the byte handler.
+                        if let Some(len) = error.error_len() {
+                            out.push(REPLACEMENT);
+                            bytes = &bytes[len..];
+                            continue;
+                        }
+                    }
+                }
+            } else if self.handler.reads_ascii_as_ascii() && byte.is_ascii() {
+                let len = bytes.iter().position(|b| !b.is_ascii()).unwrap_or(bytes.len());
+                out.extend(bytes[..len].iter().map(|&b| b as char));
+                bytes = &bytes[len..];
+                continue;
+            }
+
+            let Some((&byte, rest)) = bytes.split_first() else {
+                return;
+            };
+            bytes = rest;
+            let restored = self.handler.handle(Some(byte), out);
+            self.replay(restored, out);
+        }
+    }
+
+    // reads bytes a handler gave back once more.
+    fn replay(&mut self, restored: Restored, out: &mut String) {
+        for &byte in restored.as_slice() {
+            let restored = self.handler.handle(Some(byte), out);
+            self.replay(restored, out);
+        }
+    }
+}
+
+// The bytes a handler gives back to be read again, what the standard calls restoring them to the
+// queue. Never more than three.
+#[derive(Default)]
+struct Restored {
+    bytes: [u8; 3],
+    len: usize,
+}
+
+impl Restored {
+    fn none() -> Self {
+        Restored::default()
+    }
+
+    fn of(bytes: &[u8]) -> Self {
+        let mut restored = Restored::default();
+        restored.bytes[..bytes.len()].copy_from_slice(bytes);
+        restored.len = bytes.len();
+        restored
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+// The state of the decoder of each kind of encoding, from the Encoding Standard. The handlers get
+// one byte at a time, None for the end of the input.
+enum Handler {
+    Utf8 {
+        code_point: u32,
+        needed: u8,
+        seen: u8,
+        lower: u8,
+        upper: u8,
+    },
+    SingleByte(&'static [u16; 128]),
+    XUserDefined,
+    Replacement {
+        reported: bool,
+    },
+    Utf16 {
+        big_endian: bool,
+        lead_byte: Option<u8>,
+        lead_surrogate: Option<u16>,
+    },
+    // GBK is read the same way.
+    Gb18030 {
+        first: u8,
+        second: u8,
+        third: u8,
+    },
+    Big5 {
+        lead: u8,
+    },
+    EucJp {
+        lead: u8,
+        jis0212: bool,
+    },
+    Iso2022Jp {
+        state: Iso2022JpState,
+        output_state: Iso2022JpState,
+        lead: u8,
+        // set right after an escape sequence, two in a row are an error.
+        output: bool,
+    },
+    ShiftJis {
+        lead: u8,
+    },
+    EucKr {
+        lead: u8,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Iso2022JpState {
+    Ascii,
+    Roman,
+    Katakana,
+    LeadByte,
+    TrailByte,
+    EscapeStart,
+    Escape,
+}
+
+impl Handler {
+    fn new(encoding: Encoding) -> Self {
+        let single_byte = Handler::SingleByte;
+        match encoding {
+            Encoding::Utf8 => Handler::Utf8 {
+                code_point: 0,
+                needed: 0,
+                seen: 0,
+                lower: 0x80,
+                upper: 0xBF,
+            },
+            Encoding::Ibm866 => single_byte(&IBM866),
+            Encoding::Iso8859_2 => single_byte(&ISO_8859_2),
+            Encoding::Iso8859_3 => single_byte(&ISO_8859_3),
+            Encoding::Iso8859_4 => single_byte(&ISO_8859_4),
+            Encoding::Iso8859_5 => single_byte(&ISO_8859_5),
+            Encoding::Iso8859_6 => single_byte(&ISO_8859_6),
+            Encoding::Iso8859_7 => single_byte(&ISO_8859_7),
+            Encoding::Iso8859_8 | Encoding::Iso8859_8I => single_byte(&ISO_8859_8),
+            Encoding::Iso8859_10 => single_byte(&ISO_8859_10),
+            Encoding::Iso8859_13 => single_byte(&ISO_8859_13),
+            Encoding::Iso8859_14 => single_byte(&ISO_8859_14),
+            Encoding::Iso8859_15 => single_byte(&ISO_8859_15),
+            Encoding::Iso8859_16 => single_byte(&ISO_8859_16),
+            Encoding::Koi8R => single_byte(&KOI8_R),
+            Encoding::Koi8U => single_byte(&KOI8_U),
+            Encoding::Macintosh => single_byte(&MACINTOSH),
+            Encoding::Windows874 => single_byte(&WINDOWS_874),
+            Encoding::Windows1250 => single_byte(&WINDOWS_1250),
+            Encoding::Windows1251 => single_byte(&WINDOWS_1251),
+            Encoding::Windows1252 => single_byte(&WINDOWS_1252),
+            Encoding::Windows1253 => single_byte(&WINDOWS_1253),
+            Encoding::Windows1254 => single_byte(&WINDOWS_1254),
+            Encoding::Windows1255 => single_byte(&WINDOWS_1255),
+            Encoding::Windows1256 => single_byte(&WINDOWS_1256),
+            Encoding::Windows1257 => single_byte(&WINDOWS_1257),
+            Encoding::Windows1258 => single_byte(&WINDOWS_1258),
+            Encoding::XMacCyrillic => single_byte(&X_MAC_CYRILLIC),
+            Encoding::Gbk | Encoding::Gb18030 => Handler::Gb18030 {
+                first: 0,
+                second: 0,
+                third: 0,
+            },
+            Encoding::Big5 => Handler::Big5 { lead: 0 },
+            Encoding::EucJp => Handler::EucJp { lead: 0, jis0212: false },
+            Encoding::Iso2022Jp => Handler::Iso2022Jp {
+                state: Iso2022JpState::Ascii,
+                output_state: Iso2022JpState::Ascii,
+                lead: 0,
+                output: false,
+            },
+            Encoding::ShiftJis => Handler::ShiftJis { lead: 0 },
+            Encoding::EucKr => Handler::EucKr { lead: 0 },
+            Encoding::Replacement => Handler::Replacement { reported: false },
+            Encoding::Utf16Be => Handler::Utf16 {
+                big_endian: true,
+                lead_byte: None,
+                lead_surrogate: None,
+            },
+            Encoding::Utf16Le => Handler::Utf16 {
+                big_endian: false,
+                lead_byte: None,
+                lead_surrogate: None,
+            },
+            Encoding::XUserDefined => Handler::XUserDefined,
+        }
+    }
+
+    // whether an ASCII byte would come out as itself right now, which is the case for most
+    // encodings in between characters.
+    fn reads_ascii_as_ascii(&self) -> bool {
+        match self {
+            Handler::SingleByte(_) | Handler::XUserDefined => true,
+            Handler::Gb18030 { first, .. } => *first == 0,
+            Handler::Big5 { lead } | Handler::EucJp { lead, .. } | Handler::ShiftJis { lead } | Handler::EucKr { lead } => {
+                *lead == 0
+            }
+            _ => false,
+        }
+    }
+
+    fn handle(&mut self, byte: Option<u8>, out: &mut String) -> Restored {
+        match self {
+            Handler::Utf8 {
+                code_point,
+                needed,
+                seen,
+                lower,
+                upper,
+            } => {
+                let Some(byte) = byte else {
+                    if *needed != 0 {
+                        *needed = 0;
+                        out.push(REPLACEMENT);
+                    }
+                    return Restored::none();
+                };
+                if *needed == 0 {
+                    match byte {
+                        0x00..=0x7F => out.push(byte as char),
+                        0xC2..=0xDF => {
+                            *needed = 1;
+                            *code_point = u32::from(byte & 0x1F);
+                        }
+                        0xE0..=0xEF => {
+                            if byte == 0xE0 {
+                                *lower = 0xA0;
+                            } else if byte == 0xED {
+                                *upper = 0x9F;
+                            }
+                            *needed = 2;
+                            *code_point = u32::from(byte & 0xF);
+                        }
+                        0xF0..=0xF4 => {
+                            if byte == 0xF0 {
+                                *lower = 0x90;
+                            } else if byte == 0xF4 {
+                                *upper = 0x8F;
+                            }
+                            *needed = 3;
+                            *code_point = u32::from(byte & 0x7);
+                        }
+                        _ => out.push(REPLACEMENT),
+                    }
+                    return Restored::none();
+                }
+
+                let in_range = (*lower..=*upper).contains(&byte);
+                *lower = 0x80;
+                *upper = 0xBF;
+                if !in_range {
+                    *code_point = 0;
+                    *needed = 0;
+                    *seen = 0;
+                    out.push(REPLACEMENT);
+                    return Restored::of(&[byte]);
+                }
+                *code_point = (*code_point << 6) | u32::from(byte & 0x3F);
+                *seen += 1;
+                if *seen == *needed {
+                    push_code_point(out, Some(*code_point));
+                    *code_point = 0;
+                    *needed = 0;
+                    *seen = 0;
+                }
+                Restored::none()
+            }
+
+            Handler::SingleByte(index) => {
+                match byte {
+                    Some(byte @ 0x00..=0x7F) => out.push(byte as char),
+                    Some(byte) => push_code_point(out, lookup(&index[..], usize::from(byte - 0x80))),
+                    None => {}
+                }
+                Restored::none()
+            }
+
+            Handler::XUserDefined => {
+                match byte {
+                    Some(byte @ 0x00..=0x7F) => out.push(byte as char),
+                    Some(byte) => push_code_point(out, Some(0xF780 + u32::from(byte) - 0x80)),
+                    None => {}
+                }
+                Restored::none()
+            }
+
+            // the whole input is a single U+FFFD, and nothing when it is empty.
+            Handler::Replacement { reported } => {
+                if byte.is_some() && !*reported {
+                    *reported = true;
+                    out.push(REPLACEMENT);
+                }
+                Restored::none()
+            }
+
+            Handler::Utf16 {
+                big_endian,
+                lead_byte,
+                lead_surrogate,
+            } => {
+                let Some(byte) = byte else {
+                    if lead_byte.take().is_some() | lead_surrogate.take().is_some() {
+                        out.push(REPLACEMENT);
+                    }
+                    return Restored::none();
+                };
+                let Some(lead) = lead_byte.take() else {
+                    *lead_byte = Some(byte);
+                    return Restored::none();
+                };
+                let code_unit = if *big_endian {
+                    u16::from_be_bytes([lead, byte])
+                } else {
+                    u16::from_le_bytes([lead, byte])
+                };
+
+                if let Some(high) = lead_surrogate.take() {
+                    if (0xDC00..=0xDFFF).contains(&code_unit) {
+                        let code_point = 0x10000 + ((u32::from(high) - 0xD800) << 10) + (u32::from(code_unit) - 0xDC00);
+                        push_code_point(out, Some(code_point));
+                        return Restored::none();
+                    }
+                    // the code unit is read again on its own.
+                    out.push(REPLACEMENT);
+                    return Restored::of(&[lead, byte]);
+                }
+                match code_unit {
+                    0xD800..=0xDBFF => *lead_surrogate = Some(code_unit),
+                    0xDC00..=0xDFFF => out.push(REPLACEMENT),
+                    _ => push_code_point(out, Some(u32::from(code_unit))),
+                }
+                Restored::none()
+            }
+
+            Handler::Gb18030 { first, second, third } => {
+                let Some(byte) = byte else {
+                    if *first != 0 {
+                        (*first, *second, *third) = (0, 0, 0);
+                        out.push(REPLACEMENT);
+                    }
+                    return Restored::none();
+                };
+
+                if *third != 0 {
+                    let (lead, second_byte, third_byte) = (*first, *second, *third);
+                    (*first, *second, *third) = (0, 0, 0);
+                    if !(0x30..=0x39).contains(&byte) {
+                        out.push(REPLACEMENT);
+                        return Restored::of(&[second_byte, third_byte, byte]);
+                    }
+                    let pointer = (u32::from(lead) - 0x81) * (10 * 126 * 10)
+                        + (u32::from(second_byte) - 0x30) * (10 * 126)
+                        + (u32::from(third_byte) - 0x81) * 10
+                        + u32::from(byte)
+                        - 0x30;
+                    push_code_point(out, gb18030_ranges_code_point(pointer));
+                    return Restored::none();
+                }
+
+                if *second != 0 {
+                    if (0x81..=0xFE).contains(&byte) {
+                        *third = byte;
+                        return Restored::none();
+                    }
+                    let second_byte = *second;
+                    (*first, *second) = (0, 0);
+                    out.push(REPLACEMENT);
+                    return Restored::of(&[second_byte, byte]);
+                }
+
+                if *first != 0 {
+                    if (0x30..=0x39).contains(&byte) {
+                        *second = byte;
+                        return Restored::none();
+                    }
+                    let lead = *first;
+                    *first = 0;
+                    let offset = if byte < 0x7F { 0x40 } else { 0x41 };
+                    let code_point = match byte {
+                        0x40..=0x7E | 0x80..=0xFE => {
+                            lookup(&GB18030, usize::from(lead - 0x81) * 190 + usize::from(byte - offset))
+                        }
+                        _ => None,
+                    };
+                    return finish_pair(code_point, byte, out);
+                }
+
+                match byte {
+                    0x00..=0x7F => out.push(byte as char),
+                    0x80 => out.push('\u{20AC}'),
+                    0x81..=0xFE => *first = byte,
+                    _ => out.push(REPLACEMENT),
+                }
+                Restored::none()
+            }
+
+            Handler::Big5 { lead } => {
+                let Some(byte) = byte else {
+                    if *lead != 0 {
+                        *lead = 0;
+                        out.push(REPLACEMENT);
+                    }
+                    return Restored::none();
+                };
+
+                if *lead != 0 {
+                    let lead_byte = *lead;
+                    *lead = 0;
+                    let offset = if byte < 0x7F { 0x40 } else { 0x62 };
+                    let code_point = match byte {
+                        0x40..=0x7E | 0xA1..=0xFE => {
+                            let pointer = usize::from(lead_byte - 0x81) * 157 + usize::from(byte - offset);
+                            // the only pointers that are two code points.
+                            let pair = match pointer {
+                                1133 => Some("\u{CA}\u{304}"),
+                                1135 => Some("\u{CA}\u{30C}"),
+                                1164 => Some("\u{EA}\u{304}"),
+                                1166 => Some("\u{EA}\u{30C}"),
+                                _ => None,
+                            };
+                            if let Some(pair) = pair {
+                                out.push_str(pair);
+                                return Restored::none();
+                            }
+                            lookup(&BIG5, pointer)
+                        }
+                        _ => None,
+                    };
+                    return finish_pair(code_point, byte, out);
+                }
+
+                match byte {
+                    0x00..=0x7F => out.push(byte as char),
+                    0x81..=0xFE => *lead = byte,
+                    _ => out.push(REPLACEMENT),
+                }
+                Restored::none()
+            }
+
+            Handler::EucJp { lead, jis0212 } => {
+                let Some(byte) = byte else {
+                    if *lead != 0 {
+                        *lead = 0;
+                        out.push(REPLACEMENT);
+                    }
+                    return Restored::none();
+                };
+
+                if *lead == 0x8E && (0xA1..=0xDF).contains(&byte) {
+                    *lead = 0;
+                    push_code_point(out, Some(0xFF61 - 0xA1 + u32::from(byte)));
+                    return Restored::none();
+                }
+                if *lead == 0x8F && (0xA1..=0xFE).contains(&byte) {
+                    *jis0212 = true;
+                    *lead = byte;
+                    return Restored::none();
+                }
+                if *lead != 0 {
+                    let lead_byte = *lead;
+                    *lead = 0;
+                    let code_point = if (0xA1..=0xFE).contains(&lead_byte) && (0xA1..=0xFE).contains(&byte) {
+                        let pointer = usize::from(lead_byte - 0xA1) * 94 + usize::from(byte - 0xA1);
+                        if *jis0212 {
+                            lookup(&JIS0212, pointer)
+                        } else {
+                            lookup(&JIS0208, pointer)
+                        }
+                    } else {
+                        None
+                    };
+                    *jis0212 = false;
+                    return finish_pair(code_point, byte, out);
+                }
+
+                match byte {
+                    0x00..=0x7F => out.push(byte as char),
+                    0x8E | 0x8F | 0xA1..=0xFE => *lead = byte,
+                    _ => out.push(REPLACEMENT),
+                }
+                Restored::none()
+            }
+
+            Handler::Iso2022Jp {
+                state,
+                output_state,
+                lead,
+                output,
+            } => {
+                use Iso2022JpState::*;
+
+                match (*state, byte) {
+                    (Ascii | Roman | Katakana | LeadByte, Some(0x1B)) => *state = EscapeStart,
+                    (Ascii | Roman | Katakana | LeadByte, None) => {}
+                    (Ascii, Some(byte @ 0x00..=0x7F)) if byte != 0x0E && byte != 0x0F => {
+                        *output = false;
+                        out.push(byte as char);
+                    }
+                    (Roman, Some(byte @ 0x00..=0x7F)) if byte != 0x0E && byte != 0x0F => {
+                        *output = false;
+                        out.push(match byte {
+                            0x5C => '\u{A5}',
+                            0x7E => '\u{203E}',
+                            _ => byte as char,
+                        });
+                    }
+                    (Katakana, Some(byte @ 0x21..=0x5F)) => {
+                        *output = false;
+                        push_code_point(out, Some(0xFF61 - 0x21 + u32::from(byte)));
+                    }
+                    (LeadByte, Some(byte @ 0x21..=0x7E)) => {
+                        *output = false;
+                        *lead = byte;
+                        *state = TrailByte;
+                    }
+                    (Ascii | Roman | Katakana | LeadByte, Some(_)) => {
+                        *output = false;
+                        out.push(REPLACEMENT);
+                    }
+
+                    (TrailByte, Some(0x1B)) => {
+                        *state = EscapeStart;
+                        out.push(REPLACEMENT);
+                    }
+                    (TrailByte, Some(byte @ 0x21..=0x7E)) => {
+                        *state = LeadByte;
+                        let pointer = usize::from(*lead - 0x21) * 94 + usize::from(byte - 0x21);
+                        push_code_point(out, lookup(&JIS0208, pointer));
+                    }
+                    // the end of the input is read again in the lead byte state, which is done
+                    // there.
+                    (TrailByte, _) => {
+                        *state = LeadByte;
+                        out.push(REPLACEMENT);
+                    }
+
+                    (EscapeStart, Some(byte @ (0x24 | 0x28))) => {
+                        *lead = byte;
+                        *state = Escape;
+                    }
+                    (EscapeStart, _) => {
+                        *output = false;
+                        *state = *output_state;
+                        out.push(REPLACEMENT);
+                        return Restored::of(byte.as_slice());
+                    }
+
+                    (Escape, _) => {
+                        let lead_byte = *lead;
+                        *lead = 0;
+                        let next = match (lead_byte, byte) {
+                            (0x28, Some(0x42)) => Some(Ascii),
+                            (0x28, Some(0x4A)) => Some(Roman),
+                            (0x28, Some(0x49)) => Some(Katakana),
+                            (0x24, Some(0x40 | 0x42)) => Some(LeadByte),
+                            _ => None,
+                        };
+                        if let Some(next) = next {
+                            *state = next;
+                            *output_state = next;
+                            // an escape sequence right after another one is an error.
+                            if std::mem::replace(output, true) {
+                                out.push(REPLACEMENT);
+                            }
+                            return Restored::none();
+                        }
+
+                        *output = false;
+                        *state = *output_state;
+                        out.push(REPLACEMENT);
+                        return match byte {
+                            Some(byte) => Restored::of(&[lead_byte, byte]),
+                            None => Restored::of(&[lead_byte]),
+                        };
+                    }
+                }
+                Restored::none()
+            }
+
+            Handler::ShiftJis { lead } => {
+                let Some(byte) = byte else {
+                    if *lead != 0 {
+                        *lead = 0;
+                        out.push(REPLACEMENT);
+                    }
+                    return Restored::none();
+                };
+
+                if *lead != 0 {
+                    let lead_byte = *lead;
+                    *lead = 0;
+                    let offset = if byte < 0x7F { 0x40 } else { 0x41 };
+                    let lead_offset = if lead_byte < 0xA0 { 0x81 } else { 0xC1 };
+                    let code_point = match byte {
+                        0x40..=0x7E | 0x80..=0xFC => {
+                            let pointer = usize::from(lead_byte - lead_offset) * 188 + usize::from(byte - offset);
+                            // the user-defined characters go to the private use area.
+                            if (8836..=10715).contains(&pointer) {
+                                Some(0xE000 - 8836 + pointer as u32)
+                            } else {
+                                lookup(&JIS0208, pointer)
+                            }
+                        }
+                        _ => None,
+                    };
+                    return finish_pair(code_point, byte, out);
+                }
+
+                match byte {
+                    0x00..=0x80 => push_code_point(out, Some(u32::from(byte))),
+                    0xA1..=0xDF => push_code_point(out, Some(0xFF61 - 0xA1 + u32::from(byte))),
+                    0x81..=0x9F | 0xE0..=0xFC => *lead = byte,
+                    _ => out.push(REPLACEMENT),
+                }
+                Restored::none()
+            }
+
+            Handler::EucKr { lead } => {
+                let Some(byte) = byte else {
+                    if *lead != 0 {
+                        *lead = 0;
+                        out.push(REPLACEMENT);
+                    }
+                    return Restored::none();
+                };
+
+                if *lead != 0 {
+                    let lead_byte = *lead;
+                    *lead = 0;
+                    let code_point = match byte {
+                        0x41..=0xFE => lookup(&EUC_KR, usize::from(lead_byte - 0x81) * 190 + usize::from(byte - 0x41)),
+                        _ => None,
+                    };
+                    return finish_pair(code_point, byte, out);
+                }
+
+                match byte {
+                    0x00..=0x7F => out.push(byte as char),
+                    0x81..=0xFE => *lead = byte,
+                    _ => out.push(REPLACEMENT),
+                }
+                Restored::none()
+            }
+        }
+    }
+}
+
+// The end of a two-byte sequence in the multi-byte encodings: its code point if it has one,
+// otherwise U+FFFD, and an ASCII second byte is read again on its own so that a broken sequence
+// can not swallow a "<".
+fn finish_pair(code_point: Option<u32>, byte: u8, out: &mut String) -> Restored {
+    if code_point.is_some() {
+        push_code_point(out, code_point);
+        return Restored::none();
+    }
+    out.push(REPLACEMENT);
+    if byte.is_ascii() {
+        Restored::of(&[byte])
+    } else {
+        Restored::none()
+    }
+}
+
+// the code point of pointer in index, the indexes use 0 for pointers without one.
+fn lookup<T: Copy + Into<u32>>(index: &[T], pointer: usize) -> Option<u32> {
+    index.get(pointer).map(|&code_point| code_point.into()).filter(|&code_point| code_point != 0)
+}
+
+// "index gb18030 ranges code point", for the four-byte sequences.
+fn gb18030_ranges_code_point(pointer: u32) -> Option<u32> {
+    if (pointer > 39419 && pointer < 189000) || pointer > 1237575 {
+        return None;
+    }
+    // swapped with U+1E3F by GB18030-2005.
+    if pointer == 7457 {
+        return Some(0xE7C7);
+    }
+    if pointer >= 189000 {
+        return Some(0x10000 + pointer - 189000);
+    }
+    let index = match GB18030_RANGES.binary_search_by_key(&pointer, |&(start, _)| start) {
+        Ok(index) => index,
+        Err(index) => index - 1,
+    };
+    let (start, code_point) = GB18030_RANGES[index];
+    Some(code_point + pointer - start)
+}
+
+fn push_code_point(out: &mut String, code_point: Option<u32>) {
+    out.push(code_point.and_then(char::from_u32).unwrap_or(REPLACEMENT));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Encoding;
+
+    // bytes and the text they decode to, a character or two from the range that needs the
+    // encoding, for every encoding.
+    fn samples() -> Vec<(Encoding, &'static [u8], &'static str)> {
+        vec![
+            (Encoding::Utf8, &b"h\xC3\xA9llo \xF0\x9F\x98\x80"[..], "héllo 😀"),
+            (Encoding::Ibm866, &b"\x8F\xE0\xA8\xA2\xA5\xE2, \xAC\xA8\xE0"[..], "Привет, мир"),
+            (Encoding::Iso8859_2, &b"\xAF\xF3\xB3\xE6 \xB3\xB1ka"[..], "Żółć łąka"),
+            (Encoding::Iso8859_3, &b"\xA1\xF5\xBF \xE5 \xFD"[..], "Ħġż ċ ŭ"),
+            (Encoding::Iso8859_4, &b"\xD3\xEF\xB6\xF1 \xB3"[..], "Ķīļņ ŗ"),
+            (Encoding::Iso8859_5, &b"\xBF\xE0\xD8\xD2\xD5\xE2"[..], "Привет"),
+            (Encoding::Iso8859_6, &b"\xE5\xD1\xCD\xC8\xC7"[..], "مرحبا"),
+            (Encoding::Iso8859_7, &b"\xC3\xE5\xE9\xDC \xF3\xEF\xF5"[..], "Γειά σου"),
+            (Encoding::Iso8859_8, &b"\xF9\xEC\xE5\xED"[..], "שלום"),
+            (Encoding::Iso8859_8I, &b"\xF9\xEC\xE5\xED"[..], "שלום"),
+            (Encoding::Iso8859_10, &b"\xDEj\xF3\xF0 \xBF"[..], "Þjóð ŋ"),
+            (Encoding::Iso8859_13, &b"\xC0\xE8\xE6\xEB\xE1 \xF0\xF8"[..], "Ąčęėį šų"),
+            (Encoding::Iso8859_14, &b"\xD0\xFE \xA2"[..], "Ŵŷ ḃ"),
+            (Encoding::Iso8859_15, &b"\xA4 \xBD \xBE"[..], "€ œ Ÿ"),
+            (Encoding::Iso8859_16, &b"\xC3\xBA\xFE \xA4"[..], "Ășț €"),
+            (Encoding::Koi8R, &b"\xF0\xD2\xC9\xD7\xC5\xD4"[..], "Привет"),
+            (Encoding::Koi8U, &b"\xF0\xD2\xC9\xD7\xA6\xD4, \xAD"[..], "Привіт, ґ"),
+            (Encoding::Macintosh, &b"caf\x8E \xB7 \xB9"[..], "café ∑ π"),
+            (Encoding::Windows874, &b"\xCA\xC7\xD1\xCA\xB4\xD5"[..], "สวัสดี"),
+            (Encoding::Windows1250, &b"\xAF\xF3\xB3\xE6"[..], "Żółć"),
+            (Encoding::Windows1251, &b"\xCF\xF0\xE8\xE2\xE5\xF2"[..], "Привет"),
+            (Encoding::Windows1252, &b"caf\xE9 \x93\x80\x94"[..], "café “€”"),
+            (Encoding::Windows1253, &b"\xC3\xE5\xE9\xDC"[..], "Γειά"),
+            (Encoding::Windows1254, &b"\xDD\xF0\xFE"[..], "İğş"),
+            (Encoding::Windows1255, &b"\xF9\xEC\xE5\xED"[..], "שלום"),
+            (Encoding::Windows1256, &b"\xE3\xD1\xCD\xC8\xC7"[..], "مرحبا"),
+            (Encoding::Windows1257, &b"\xC0\xE8\xE6\xEB"[..], "Ąčęė"),
+            (Encoding::Windows1258, &b"\xD0\xF5n \xFE"[..], "Đơn ₫"),
+            (Encoding::XMacCyrillic, &b"\x8F\xF0\xE8\xE2\xE5\xF2"[..], "Привет"),
+            (Encoding::Gbk, &b"\xC4\xE3\xBA\xC3\xA3\xAC\xCA\xC0\xBD\xE7"[..], "你好，世界"),
+            (Encoding::Gb18030, &b"\xC4\xE3\xBA\xC3\xA2\xE3\x949\xFC6"[..], "你好€😀"),
+            (Encoding::Big5, &b"\xA7A\xA6n\xA1A\xA5@\xAC\xC9"[..], "你好，世界"),
+            (Encoding::EucJp, &b"\xA4\xB3\xA4\xF3\xA4\xCB\xA4\xC1\xA4\xCF\xA1\xA2\xC0\xA4\xB3\xA6"[..], "こんにちは、世界"),
+            (Encoding::Iso2022Jp, &b"a\x1B$B$3$s$K$A$O!\x22@$3&\x1B(B"[..], "aこんにちは、世界"),
+            (Encoding::ShiftJis, &b"\x82\xB1\x82\xF1\x82\xC9\x82\xBF\x82\xCD\x81A\x90\xA2\x8AE\xB1"[..], "こんにちは、世界ｱ"),
+            (Encoding::EucKr, &b"\xBE\xC8\xB3\xE7\xC7\xCF\xBC\xBC\xBF\xE4"[..], "안녕하세요"),
+            (Encoding::Utf16Be, &b"\x00h\x00\xE9\x00l\x00l\x00o\x00 \xD8=\xDE\x00"[..], "héllo 😀"),
+            (Encoding::Utf16Le, &b"h\x00\xE9\x00l\x00l\x00o\x00 \x00=\xD8\x00\xDE"[..], "héllo 😀"),
+            (Encoding::XUserDefined, &b"a\x80\xFF"[..], "a\u{F780}\u{F7FF}"),
+            (Encoding::Replacement, &b"abc"[..], "\u{FFFD}"),
+        ]
+    }
+
+    // decodes bytes given one at a time.
+    fn decode_byte_by_byte(encoding: Encoding, bytes: &[u8]) -> String {
+        let mut decoder = encoding.new_decoder();
+        let mut text = String::new();
+        for byte in bytes {
+            decoder.decode(std::slice::from_ref(byte), &mut text);
+        }
+        decoder.finish(&mut text);
+        text
+    }
+
+    #[test]
+    fn every_encoding() {
+        for (encoding, bytes, expected) in samples() {
+            assert_eq!(encoding.decode(bytes), (expected.to_string(), encoding), "{}", encoding);
+            assert_eq!(decode_byte_by_byte(encoding, bytes), expected, "{}", encoding);
+        }
+    }
+
+    // Errors can be found a byte after the character they cut short, which gets handed back to
+    // be read again. That has to come out the same when the next byte is in the next chunk.
+    #[test]
+    fn every_encoding_with_errors() {
+        for (encoding, bytes, _) in samples() {
+            for junk in [&b"\x81"[..], b"\x81<", b"\xFF\xFE", b"\x1B$", b"\x1B(Ja", b"\x84\x31\x82", b"\xD8\x00a"] {
+                let mut bytes = bytes.to_vec();
+                let middle = bytes.len() / 2;
+                bytes.splice(middle..middle, junk.iter().copied());
+                bytes.extend_from_slice(junk);
+                let (text, _) = encoding.decode(&bytes);
+                assert_eq!(decode_byte_by_byte(encoding, &bytes), text, "{} {:?}", encoding, bytes);
+            }
+        }
+    }
+}
@@ -0,0 +1,596 @@
+use std::fmt;
+
+use crate::decoder::Decoder;
+
+// The encodings of the WHATWG Encoding Standard, which are the only ones a page can be in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    Utf8,
+    // the legacy single-byte encodings.
+    Ibm866,
+    Iso8859_2,
+    Iso8859_3,
+    Iso8859_4,
+    Iso8859_5,
+    Iso8859_6,
+    Iso8859_7,
+    Iso8859_8,
+    Iso8859_8I,
+    Iso8859_10,
+    Iso8859_13,
+    Iso8859_14,
+    Iso8859_15,
+    Iso8859_16,
+    Koi8R,
+    Koi8U,
+    Macintosh,
+    Windows874,
+    Windows1250,
+    Windows1251,
+    Windows1252,
+    Windows1253,
+    Windows1254,
+    Windows1255,
+    Windows1256,
+    Windows1257,
+    Windows1258,
+    XMacCyrillic,
+    // the legacy multi-byte encodings.
+    Gbk,
+    Gb18030,
+    Big5,
+    EucJp,
+    Iso2022Jp,
+    ShiftJis,
+    EucKr,
+    // what ISO-2022-KR, HZ and friends are mapped to, they were used for attacks more than for
+    // pages. Decodes to a single U+FFFD.
+    Replacement,
+    Utf16Be,
+    Utf16Le,
+    XUserDefined,
+}
+
+// how sure the sniffing is about the encoding. A tentative one can still be changed by a <meta>
+// which the tree builder finds further down than the prescan looked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Confidence {
+    Certain,
+    Tentative,
+}
+
+impl Encoding {
+    // the encoding's name, which is what document.characterSet returns.
+    pub fn name(self) -> &'static str {
+        match self {
+            Encoding::Utf8 => "UTF-8",
+            Encoding::Ibm866 => "IBM866",
+            Encoding::Iso8859_2 => "ISO-8859-2",
+            Encoding::Iso8859_3 => "ISO-8859-3",
+            Encoding::Iso8859_4 => "ISO-8859-4",
+            Encoding::Iso8859_5 => "ISO-8859-5",
+            Encoding::Iso8859_6 => "ISO-8859-6",
+            Encoding::Iso8859_7 => "ISO-8859-7",
+            Encoding::Iso8859_8 => "ISO-8859-8",
+            Encoding::Iso8859_8I => "ISO-8859-8-I",
+            Encoding::Iso8859_10 => "ISO-8859-10",
+            Encoding::Iso8859_13 => "ISO-8859-13",
+            Encoding::Iso8859_14 => "ISO-8859-14",
+            Encoding::Iso8859_15 => "ISO-8859-15",
+            Encoding::Iso8859_16 => "ISO-8859-16",
+            Encoding::Koi8R => "KOI8-R",
+            Encoding::Koi8U => "KOI8-U",
+            Encoding::Macintosh => "macintosh",
+            Encoding::Windows874 => "windows-874",
+            Encoding::Windows1250 => "windows-1250",
+            Encoding::Windows1251 => "windows-1251",
+            Encoding::Windows1252 => "windows-1252",
+            Encoding::Windows1253 => "windows-1253",
+            Encoding::Windows1254 => "windows-1254",
+            Encoding::Windows1255 => "windows-1255",
+            Encoding::Windows1256 => "windows-1256",
+            Encoding::Windows1257 => "windows-1257",
+            Encoding::Windows1258 => "windows-1258",
+            Encoding::XMacCyrillic => "x-mac-cyrillic",
+            Encoding::Gbk => "GBK",
+            Encoding::Gb18030 => "gb18030",
+            Encoding::Big5 => "Big5",
+            Encoding::EucJp => "EUC-JP",
+            Encoding::Iso2022Jp => "ISO-2022-JP",
+            Encoding::ShiftJis => "Shift_JIS",
+            Encoding::EucKr => "EUC-KR",
+            Encoding::Replacement => "replacement",
+            Encoding::Utf16Be => "UTF-16BE",
+            Encoding::Utf16Le => "UTF-16LE",
+            Encoding::XUserDefined => "x-user-defined",
+        }
+    }
+
+    // "get an encoding": labels are matched without case and surrounding whitespace, so
+    // " Latin1" is windows-1252.
+    pub fn for_label(label: &str) -> Option<Encoding> {
+        let label = label.trim_matches(is_whitespace).to_ascii_lowercase();
+        LABELS
+            .binary_search_by_key(&label.as_str(), |(label, _)| label)
+            .ok()
+            .map(|index| LABELS[index].1)
+    }
+
+    // The encoding sniffing algorithm, on the first bytes of a page. A byte order mark is
+    // certain, and so is transport_charset, the charset parameter of the Content-Type header, as
+    // long as it is a label. Otherwise a <meta> found by the prescan is tentative, and without
+    // one it is a tentative windows-1252, the default of most locales. Guessing from the
+    // bytes themselves is left out.
+    pub fn sniff(bytes: &[u8], transport_charset: Option<&str>) -> (Encoding, Confidence) {
+        if let Some(encoding) = Encoding::for_bom(bytes) {
+            return (encoding, Confidence::Certain);
+        }
+        if let Some(encoding) = transport_charset.and_then(Encoding::for_label) {
+            return (encoding, Confidence::Certain);
+        }
+        if let Some(encoding) = Encoding::prescan(bytes) {
+            return (encoding, Confidence::Tentative);
+        }
+        (Encoding::Windows1252, Confidence::Tentative)
+    }
+
+    pub fn new_decoder(self) -> Decoder {
+        Decoder::new(self)
+    }
+
+    // "decode": all of bytes at once, returning the text and the encoding it turned out to be in,
+    // which is not self when there is a byte order mark.
+    pub fn decode(self, bytes: &[u8]) -> (String, Encoding) {
+        let mut decoder = self.new_decoder();
+        let mut text = String::with_capacity(bytes.len());
+        decoder.decode(bytes, &mut text);
+        decoder.finish(&mut text);
+        (text, decoder.encoding())
+    }
+
+    // "BOM sniff"
+    pub fn for_bom(bytes: &[u8]) -> Option<Encoding> {
+        if bytes.starts_with(b"\xEF\xBB\xBF") {
+            Some(Encoding::Utf8)
+        } else if bytes.starts_with(b"\xFE\xFF") {
+            Some(Encoding::Utf16Be)
+        } else if bytes.starts_with(b"\xFF\xFE") {
+            Some(Encoding::Utf16Le)
+        } else {
+            None
+        }
+    }
+
+    // "prescan a byte stream to determine its encoding": looks for a <meta charset> or a
+    // <meta http-equiv="Content-Type" content="...; charset=..."> in the first 1024 bytes,
+    // skipping over comments and the attributes of other tags. A <meta> claiming UTF-16 means
+    // UTF-8, the prescan could not have read it otherwise, and x-user-defined means windows-1252.
+    pub fn prescan(bytes: &[u8]) -> Option<Encoding> {
+        let bytes = &bytes[..bytes.len().min(1024)];
+        let mut position = 0;
+        while position < bytes.len() {
+            let rest = &bytes[position..];
+            if rest.starts_with(b"<!--") {
+                // the "--" of the "<!--" counts, so "<!-->" is a whole comment.
+                position += find(&rest[2..], b"-->")? + 4;
+            } else if starts_with_ignore_ascii_case(rest, b"<meta")
+                && rest.get(5).is_some_and(|&b| is_whitespace_byte(b) || b == b'/')
+            {
+                position += 6;
+                if let Some(encoding) = prescan_meta(bytes, &mut position) {
+                    return Some(match encoding {
+                        Encoding::Utf16Be | Encoding::Utf16Le => Encoding::Utf8,
+                        Encoding::XUserDefined => Encoding::Windows1252,
+                        encoding => encoding,
+                    });
+                }
+            } else if rest.len() > 1
+                && rest[0] == b'<'
+                && (rest[1].is_ascii_alphabetic() || (rest[1] == b'/' && rest.get(2).is_some_and(u8::is_ascii_alphabetic)))
+            {
+                position += rest.iter().position(|&b| is_whitespace_byte(b) || b == b'>')?;
+                while get_attribute(bytes, &mut position)?.is_some() {}
+            } else if rest.starts_with(b"<!") || rest.starts_with(b"</") || rest.starts_with(b"<?") {
+                position += rest.iter().position(|&b| b == b'>')?;
+            }
+            position += 1;
+        }
+        None
+    }
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+// The attributes of a <meta> which position points just past the "<meta " of, up to the
+// encoding it declares. None if it does not declare one, only has a content attribute without
+// the http-equiv="content-type" that makes it count, or gets cut off.
+fn prescan_meta(bytes: &[u8], position: &mut usize) -> Option<Encoding> {
+    let mut seen = Vec::new();
+    let mut got_pragma = false;
+    // None until a charset or a content attribute with a charset in it shows up.
+    let mut need_pragma = None;
+    let mut charset = None;
+
+    while let Some((name, value)) = get_attribute(bytes, position)? {
+        if seen.contains(&name) {
+            continue;
+        }
+        match name.as_slice() {
+            b"http-equiv" if value == b"content-type" => {
+                got_pragma = true;
+            }
+            b"content" if charset.is_none() => {
+                if let Some(encoding) = charset_from_content(&value) {
+                    charset = Some(encoding);
+                    need_pragma = Some(true);
+                }
+            }
+            b"charset" => {
+                charset = std::str::from_utf8(&value).ok().and_then(Encoding::for_label);
+                need_pragma = Some(false);
+            }
+            _ => {}
+        }
+        seen.push(name);
+    }
+
+    match need_pragma {
+        Some(true) if !got_pragma => None,
+        Some(_) => charset,
+        None => None,
+    }
+}
+
+// "get an attribute", which lowercases both the name and the value. The outer None is for
+// running out of bytes, which ends the prescan, the inner one for the end of the tag.
+fn get_attribute(bytes: &[u8], position: &mut usize) -> Option<Option<(Vec<u8>, Vec<u8>)>> {
+    let byte_at = |position: usize| bytes.get(position).copied();
+
+    while is_whitespace_byte(byte_at(*position)?) || byte_at(*position)? == b'/' {
+        *position += 1;
+    }
+    if byte_at(*position)? == b'>' {
+        return Some(None);
+    }
+
+    let mut name = Vec::new();
+    let mut value = Vec::new();
+    loop {
+        match byte_at(*position)? {
+            b'=' if !name.is_empty() => {
+                *position += 1;
+                break;
+            }
+            b if is_whitespace_byte(b) => {
+                while is_whitespace_byte(byte_at(*position)?) {
+                    *position += 1;
+                }
+                if byte_at(*position)? != b'=' {
+                    return Some(Some((name, value)));
+                }
+                *position += 1;
+                break;
+            }
+            b'/' | b'>' => return Some(Some((name, value))),
+            b => name.push(b.to_ascii_lowercase()),
+        }
+        *position += 1;
+    }
+
+    while is_whitespace_byte(byte_at(*position)?) {
+        *position += 1;
+    }
+    match byte_at(*position)? {
+        quote @ (b'"' | b'\'') => loop {
+            *position += 1;
+            match byte_at(*position)? {
+                b if b == quote => {
+                    *position += 1;
+                    return Some(Some((name, value)));
+                }
+                b => value.push(b.to_ascii_lowercase()),
+            }
+        },
+        b'>' => return Some(Some((name, value))),
+        b => value.push(b.to_ascii_lowercase()),
+    }
+    loop {
+        *position += 1;
+        match byte_at(*position)? {
+            b if is_whitespace_byte(b) || b == b'>' => return Some(Some((name, value))),
+            b => value.push(b.to_ascii_lowercase()),
+        }
+    }
+}
+
+// "extracting a character encoding from a meta element", the charset=... in a content
+// attribute like "text/html; charset=utf-8".
+fn charset_from_content(content: &[u8]) -> Option<Encoding> {
+    let mut position = 0;
+    loop {
+        position += find_ignore_ascii_case(&content[position..], b"charset")? + b"charset".len();
+        let after = skip_whitespace(content, position);
+        if content.get(after) != Some(&b'=') {
+            continue;
+        }
+
+        let start = skip_whitespace(content, after + 1);
+        let value = match *content.get(start)? {
+            quote @ (b'"' | b'\'') => {
+                let len = content[start + 1..].iter().position(|&b| b == quote)?;
+                &content[start + 1..start + 1 + len]
+            }
+            _ => {
+                let len = content[start..]
+                    .iter()
+                    .position(|&b| is_whitespace_byte(b) || b == b';')
+                    .unwrap_or(content.len() - start);
+                &content[start..start + len]
+            }
+        };
+        return std::str::from_utf8(value).ok().and_then(Encoding::for_label);
+    }
+}
+
+fn is_whitespace(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\x0C' | '\r' | ' ')
+}
+
+fn is_whitespace_byte(b: u8) -> bool {
+    matches!(b, b'\t' | b'\n' | b'\x0C' | b'\r' | b' ')
+}
+
+fn skip_whitespace(bytes: &[u8], mut position: usize) -> usize {
+    while bytes.get(position).copied().is_some_and(is_whitespace_byte) {
+        position += 1;
+    }
+    position
+}
+
+fn starts_with_ignore_ascii_case(bytes: &[u8], prefix: &[u8]) -> bool {
+    bytes.len() >= prefix.len() && bytes[..prefix.len()].eq_ignore_ascii_case(prefix)
+}
+
+fn find(bytes: &[u8], needle: &[u8]) -> Option<usize> {
+    bytes.windows(needle.len()).position(|window| window == needle)
+}
+
+fn find_ignore_ascii_case(bytes: &[u8], needle: &[u8]) -> Option<usize> {
+    bytes.windows(needle.len()).position(|window| window.eq_ignore_ascii_case(needle))
+}
+
+// every label of the Encoding Standard, sorted for the binary search.
+static LABELS: [(&str, Encoding); 228] = [
+    ("866", Encoding::Ibm866),
+    ("ansi_x3.4-1968", Encoding::Windows1252),
+    ("arabic", Encoding::Iso8859_6),
+    ("ascii", Encoding::Windows1252),
+    ("asmo-708", Encoding::Iso8859_6),
+    ("big5", Encoding::Big5),
+    ("big5-hkscs", Encoding::Big5),
+    ("chinese", Encoding::Gbk),
+    ("cn-big5", Encoding::Big5),
+    ("cp1250", Encoding::Windows1250),
+    ("cp1251", Encoding::Windows1251),
+    ("cp1252", Encoding::Windows1252),
+    ("cp1253", Encoding::Windows1253),
+    ("cp1254", Encoding::Windows1254),
+    ("cp1255", Encoding::Windows1255),
+    ("cp1256", Encoding::Windows1256),
+    ("cp1257", Encoding::Windows1257),
+    ("cp1258", Encoding::Windows1258),
+    ("cp819", Encoding::Windows1252),
+    ("cp866", Encoding::Ibm866),
+    ("csbig5", Encoding::Big5),
+    ("cseuckr", Encoding::EucKr),
+    ("cseucpkdfmtjapanese", Encoding::EucJp),
+    ("csgb2312", Encoding::Gbk),
+    ("csibm866", Encoding::Ibm866),
+    ("csiso2022jp", Encoding::Iso2022Jp),
+    ("csiso2022kr", Encoding::Replacement),
+    ("csiso58gb231280", Encoding::Gbk),
+    ("csiso88596e", Encoding::Iso8859_6),
+    ("csiso88596i", Encoding::Iso8859_6),
+    ("csiso88598e", Encoding::Iso8859_8),
+    ("csiso88598i", Encoding::Iso8859_8I),
+    ("csisolatin1", Encoding::Windows1252),
+    ("csisolatin2", Encoding::Iso8859_2),
+    ("csisolatin3", Encoding::Iso8859_3),
+    ("csisolatin4", Encoding::Iso8859_4),
+    ("csisolatin5", Encoding::Windows1254),
+    ("csisolatin6", Encoding::Iso8859_10),
+    ("csisolatin9", Encoding::Iso8859_15),
+    ("csisolatinarabic", Encoding::Iso8859_6),
+    ("csisolatincyrillic", Encoding::Iso8859_5),
+    ("csisolatingreek", Encoding::Iso8859_7),
+    ("csisolatinhebrew", Encoding::Iso8859_8),
+    ("cskoi8r", Encoding::Koi8R),
+    ("csksc56011987", Encoding::EucKr),
+    ("csmacintosh", Encoding::Macintosh),
+    ("csshiftjis", Encoding::ShiftJis),
+    ("csunicode", Encoding::Utf16Le),
+    ("cyrillic", Encoding::Iso8859_5),
+    ("dos-874", Encoding::Windows874),
+    ("ecma-114", Encoding::Iso8859_6),
+    ("ecma-118", Encoding::Iso8859_7),
+    ("elot_928", Encoding::Iso8859_7),
+    ("euc-jp", Encoding::EucJp),
+    ("euc-kr", Encoding::EucKr),
+    ("gb18030", Encoding::Gb18030),
+    ("gb2312", Encoding::Gbk),
+    ("gb_2312", Encoding::Gbk),
+    ("gb_2312-80", Encoding::Gbk),
+    ("gbk", Encoding::Gbk),
+    ("greek", Encoding::Iso8859_7),
+    ("greek8", Encoding::Iso8859_7),
+    ("hebrew", Encoding::Iso8859_8),
+    ("hz-gb-2312", Encoding::Replacement),
+    ("ibm819", Encoding::Windows1252),
+    ("ibm866", Encoding::Ibm866),
+    ("iso-10646-ucs-2", Encoding::Utf16Le),
+    ("iso-2022-cn", Encoding::Replacement),
+    ("iso-2022-cn-ext", Encoding::Replacement),
+    ("iso-2022-jp", Encoding::Iso2022Jp),
+    ("iso-2022-kr", Encoding::Replacement),
+    ("iso-8859-1", Encoding::Windows1252),
+    ("iso-8859-10", Encoding::Iso8859_10),
+    ("iso-8859-11", Encoding::Windows874),
+    ("iso-8859-13", Encoding::Iso8859_13),
+    ("iso-8859-14", Encoding::Iso8859_14),
+    ("iso-8859-15", Encoding::Iso8859_15),
+    ("iso-8859-16", Encoding::Iso8859_16),
+    ("iso-8859-2", Encoding::Iso8859_2),
+    ("iso-8859-3", Encoding::Iso8859_3),
+    ("iso-8859-4", Encoding::Iso8859_4),
+    ("iso-8859-5", Encoding::Iso8859_5),
+    ("iso-8859-6", Encoding::Iso8859_6),
+    ("iso-8859-6-e", Encoding::Iso8859_6),
+    ("iso-8859-6-i", Encoding::Iso8859_6),
+    ("iso-8859-7", Encoding::Iso8859_7),
+    ("iso-8859-8", Encoding::Iso8859_8),
+    ("iso-8859-8-e", Encoding::Iso8859_8),
+    ("iso-8859-8-i", Encoding::Iso8859_8I),
+    ("iso-8859-9", Encoding::Windows1254),
+    ("iso-ir-100", Encoding::Windows1252),
+    ("iso-ir-101", Encoding::Iso8859_2),
+    ("iso-ir-109", Encoding::Iso8859_3),
+    ("iso-ir-110", Encoding::Iso8859_4),
+    ("iso-ir-126", Encoding::Iso8859_7),
+    ("iso-ir-127", Encoding::Iso8859_6),
+    ("iso-ir-138", Encoding::Iso8859_8),
+    ("iso-ir-144", Encoding::Iso8859_5),
+    ("iso-ir-148", Encoding::Windows1254),
+    ("iso-ir-149", Encoding::EucKr),
+    ("iso-ir-157", Encoding::Iso8859_10),
+    ("iso-ir-58", Encoding::Gbk),
+    ("iso8859-1", Encoding::Windows1252),
+    ("iso8859-10", Encoding::Iso8859_10),
+    ("iso8859-11", Encoding::Windows874),
+    ("iso8859-13", Encoding::Iso8859_13),
+    ("iso8859-14", Encoding::Iso8859_14),
+    ("iso8859-15", Encoding::Iso8859_15),
+    ("iso8859-2", Encoding::Iso8859_2),
+    ("iso8859-3", Encoding::Iso8859_3),
+    ("iso8859-4", Encoding::Iso8859_4),
+    ("iso8859-5", Encoding::Iso8859_5),
+    ("iso8859-6", Encoding::Iso8859_6),
+    ("iso8859-7", Encoding::Iso8859_7),
+    ("iso8859-8", Encoding::Iso8859_8),
+    ("iso8859-9", Encoding::Windows1254),
+    ("iso88591", Encoding::Windows1252),
+    ("iso885910", Encoding::Iso8859_10),
+    ("iso885911", Encoding::Windows874),
+    ("iso885913", Encoding::Iso8859_13),
+    ("iso885914", Encoding::Iso8859_14),
+    ("iso885915", Encoding::Iso8859_15),
+    ("iso88592", Encoding::Iso8859_2),
+    ("iso88593", Encoding::Iso8859_3),
+    ("iso88594", Encoding::Iso8859_4),
+    ("iso88595", Encoding::Iso8859_5),
+    ("iso88596", Encoding::Iso8859_6),
+    ("iso88597", Encoding::Iso8859_7),
+    ("iso88598", Encoding::Iso8859_8),
+    ("iso88599", Encoding::Windows1254),
+    ("iso_8859-1", Encoding::Windows1252),
+    ("iso_8859-15", Encoding::Iso8859_15),
+    ("iso_8859-1:1987", Encoding::Windows1252),
+    ("iso_8859-2", Encoding::Iso8859_2),
+    ("iso_8859-2:1987", Encoding::Iso8859_2),
+    ("iso_8859-3", Encoding::Iso8859_3),
+    ("iso_8859-3:1988", Encoding::Iso8859_3),
+    ("iso_8859-4", Encoding::Iso8859_4),
+    ("iso_8859-4:1988", Encoding::Iso8859_4),
+    ("iso_8859-5", Encoding::Iso8859_5),
+    ("iso_8859-5:1988", Encoding::Iso8859_5),
+    ("iso_8859-6", Encoding::Iso8859_6),
+    ("iso_8859-6:1987", Encoding::Iso8859_6),
+    ("iso_8859-7", Encoding::Iso8859_7),
+    ("iso_8859-7:1987", Encoding::Iso8859_7),
+    ("iso_8859-8", Encoding::Iso8859_8),
+    ("iso_8859-8:1988", Encoding::Iso8859_8),
+    ("iso_8859-9", Encoding::Windows1254),
+    ("iso_8859-9:1989", Encoding::Windows1254),
+    ("koi", Encoding::Koi8R),
+    ("koi8", Encoding::Koi8R),
+    ("koi8-r", Encoding::Koi8R),
+    ("koi8-ru", Encoding::Koi8U),
+    ("koi8-u", Encoding::Koi8U),
+    ("koi8_r", Encoding::Koi8R),
+    ("korean", Encoding::EucKr),
+    ("ks_c_5601-1987", Encoding::EucKr),
+    ("ks_c_5601-1989", Encoding::EucKr),
+    ("ksc5601", Encoding::EucKr),
+    ("ksc_5601", Encoding::EucKr),
+    ("l1", Encoding::Windows1252),
+    ("l2", Encoding::Iso8859_2),
+    ("l3", Encoding::Iso8859_3),
+    ("l4", Encoding::Iso8859_4),
+    ("l5", Encoding::Windows1254),
+    ("l6", Encoding::Iso8859_10),
+    ("l9", Encoding::Iso8859_15),
+    ("latin1", Encoding::Windows1252),
+    ("latin2", Encoding::Iso8859_2),
+    ("latin3", Encoding::Iso8859_3),
+    ("latin4", Encoding::Iso8859_4),
+    ("latin5", Encoding::Windows1254),
+    ("latin6", Encoding::Iso8859_10),
+    ("logical", Encoding::Iso8859_8I),
+    ("mac", Encoding::Macintosh),
+    ("macintosh", Encoding::Macintosh),
+    ("ms932", Encoding::ShiftJis),
+    ("ms_kanji", Encoding::ShiftJis),
+    ("replacement", Encoding::Replacement),
+    ("shift-jis", Encoding::ShiftJis),
+    ("shift_jis", Encoding::ShiftJis),
+    ("sjis", Encoding::ShiftJis),
+    ("sun_eu_greek", Encoding::Iso8859_7),
+    ("tis-620", Encoding::Windows874),
+    ("ucs-2", Encoding::Utf16Le),
+    ("unicode", Encoding::Utf16Le),
+    ("unicode-1-1-utf-8", Encoding::Utf8),
+    ("unicode11utf8", Encoding::Utf8),
+    ("unicode20utf8", Encoding::Utf8),
+    ("unicodefeff", Encoding::Utf16Le),
+    ("unicodefffe", Encoding::Utf16Be),
+    ("us-ascii", Encoding::Windows1252),
+    ("utf-16", Encoding::Utf16Le),
+    ("utf-16be", Encoding::Utf16Be),
+    ("utf-16le", Encoding::Utf16Le),
+    ("utf-8", Encoding::Utf8),
+    ("utf8", Encoding::Utf8),
+    ("visual", Encoding::Iso8859_8),
+    ("windows-1250", Encoding::Windows1250),
+    ("windows-1251", Encoding::Windows1251),
+    ("windows-1252", Encoding::Windows1252),
+    ("windows-1253", Encoding::Windows1253),
+    ("windows-1254", Encoding::Windows1254),
+    ("windows-1255", Encoding::Windows1255),
+    ("windows-1256", Encoding::Windows1256),
+    ("windows-1257", Encoding::Windows1257),
+    ("windows-1258", Encoding::Windows1258),
+    ("windows-31j", Encoding::ShiftJis),
+    ("windows-874", Encoding::Windows874),
+    ("windows-949", Encoding::EucKr),
+    ("x-cp1250", Encoding::Windows1250),
+    ("x-cp1251", Encoding::Windows1251),
+    ("x-cp1252", Encoding::Windows1252),
+    ("x-cp1253", Encoding::Windows1253),
+    ("x-cp1254", Encoding::Windows1254),
+    ("x-cp1255", Encoding::Windows1255),
+    ("x-cp1256", Encoding::Windows1256),
+    ("x-cp1257", Encoding::Windows1257),
+    ("x-cp1258", Encoding::Windows1258),
+    ("x-euc-jp", Encoding::EucJp),
+    ("x-gbk", Encoding::Gbk),
+    ("x-mac-cyrillic", Encoding::XMacCyrillic),
+    ("x-mac-roman", Encoding::Macintosh),
+    ("x-mac-ukrainian", Encoding::XMacCyrillic),
+    ("x-sjis", Encoding::ShiftJis),
+    ("x-unicode20utf8", Encoding::Utf8),
+    ("x-user-defined", Encoding::XUserDefined),
+    ("x-x-big5", Encoding::Big5),
+];
@@ -0,0 +1,149 @@
+use std::fmt;
+
+// One variant per parse error code defined in the "Parse errors" section of the spec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseError {
+    AbruptClosingOfEmptyComment,
+    AbruptDoctypePublicIdentifier,
+    AbruptDoctypeSystemIdentifier,
+    AbsenceOfDigitsInNumericCharacterReference,
+    CDataInHtmlContent,
+    CharacterReferenceOutsideUnicodeRange,
+    ControlCharacterInInputStream,
+    ControlCharacterReference,
+    DuplicateAttribute,
+    EndTagWithAttributes,
+    EndTagWithTrailingSolidus,
+    EofBeforeTagName,
+    EofInCData,
+    EofInComment,
+    EofInDoctype,
+    EofInScriptHtmlCommentLikeText,
+    EofInTag,
+    IncorrectlyClosedComment,
+    IncorrectlyOpenedComment,
+    InvalidCharacterSequenceAfterDoctypeName,
+    InvalidFirstCharacterOfTagName,
+    MissingAttributeValue,
+    MissingDoctypeName,
+    MissingDoctypePublicIdentifier,
+    MissingDoctypeSystemIdentifier,
+    MissingEndTagName,
+    MissingQuoteBeforeDoctypePublicIdentifier,
+    MissingQuoteBeforeDoctypeSystemIdentifier,
+    MissingSemicolonAfterCharacterReference,
+    MissingWhitespaceAfterDoctypePublicKeyword,
+    MissingWhitespaceAfterDoctypeSystemKeyword,
+    MissingWhitespaceBeforeDoctypeName,
+    MissingWhitespaceBetweenAttributes,
+    MissingWhitespaceBetweenDoctypePublicAndSystemIdentifiers,
+    NestedComment,
+    NoncharacterCharacterReference,
+    NoncharacterInInputStream,
+    // reported by the tree builder, the tokenizer never emits this one.
+    NonVoidHtmlElementStartTagWithTrailingSolidus,
+    NullCharacterReference,
+    SurrogateCharacterReference,
+    SurrogateInInputStream,
+    UnexpectedCharacterAfterDoctypeSystemIdentifier,
+    UnexpectedCharacterInAttributeName,
+    UnexpectedCharacterInUnquotedAttributeValue,
+    UnexpectedEqualsSignBeforeAttributeName,
+    UnexpectedNullCharacter,
+    UnexpectedQuestionMarkInsteadOfTagName,
+    UnexpectedSolidusInTag,
+    UnknownNamedCharacterReference,
+}
+
+impl ParseError {
+    // the error code exactly as it is written in the spec.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ParseError::AbruptClosingOfEmptyComment => "abrupt-closing-of-empty-comment",
+            ParseError::AbruptDoctypePublicIdentifier => "abrupt-doctype-public-identifier",
+            ParseError::AbruptDoctypeSystemIdentifier => "abrupt-doctype-system-identifier",
+            ParseError::AbsenceOfDigitsInNumericCharacterReference => {
+                "absence-of-digits-in-numeric-character-reference"
+            }
+            ParseError::CDataInHtmlContent => "cdata-in-html-content",
+            ParseError::CharacterReferenceOutsideUnicodeRange => {
+                "character-reference-outside-unicode-range"
+            }
+            ParseError::ControlCharacterInInputStream => "control-character-in-input-stream",
+            ParseError::ControlCharacterReference => "control-character-reference",
+            ParseError::DuplicateAttribute => "duplicate-attribute",
+            ParseError::EndTagWithAttributes => "end-tag-with-attributes",
+            ParseError::EndTagWithTrailingSolidus => "end-tag-with-trailing-solidus",
+            ParseError::EofBeforeTagName => "eof-before-tag-name",
+            ParseError::EofInCData => "eof-in-cdata",
+            ParseError::EofInComment => "eof-in-comment",
+            ParseError::EofInDoctype => "eof-in-doctype",
+            ParseError::EofInScriptHtmlCommentLikeText => "eof-in-script-html-comment-like-text",
+            ParseError::EofInTag => "eof-in-tag",
+            ParseError::IncorrectlyClosedComment => "incorrectly-closed-comment",
+            ParseError::IncorrectlyOpenedComment => "incorrectly-opened-comment",
+            ParseError::InvalidCharacterSequenceAfterDoctypeName => {
+                "invalid-character-sequence-after-doctype-name"
+            }
+            ParseError::InvalidFirstCharacterOfTagName => "invalid-first-character-of-tag-name",
+            ParseError::MissingAttributeValue => "missing-attribute-value",
+            ParseError::MissingDoctypeName => "missing-doctype-name",
+            ParseError::MissingDoctypePublicIdentifier => "missing-doctype-public-identifier",
+            ParseError::MissingDoctypeSystemIdentifier => "missing-doctype-system-identifier",
+            ParseError::MissingEndTagName => "missing-end-tag-name",
+            ParseError::MissingQuoteBeforeDoctypePublicIdentifier => {
+                "missing-quote-before-doctype-public-identifier"
+            }
+            ParseError::MissingQuoteBeforeDoctypeSystemIdentifier => {
+                "missing-quote-before-doctype-system-identifier"
+            }
+            ParseError::MissingSemicolonAfterCharacterReference => {
+                "missing-semicolon-after-character-reference"
+            }
+            ParseError::MissingWhitespaceAfterDoctypePublicKeyword => {
+                "missing-whitespace-after-doctype-public-keyword"
+            }
+            ParseError::MissingWhitespaceAfterDoctypeSystemKeyword => {
+                "missing-whitespace-after-doctype-system-keyword"
+            }
+            ParseError::MissingWhitespaceBeforeDoctypeName => "missing-whitespace-before-doctype-name",
+            ParseError::MissingWhitespaceBetweenAttributes => "missing-whitespace-between-attributes",
+            ParseError::MissingWhitespaceBetweenDoctypePublicAndSystemIdentifiers => {
+                "missing-whitespace-between-doctype-public-and-system-identifiers"
+            }
+            ParseError::NestedComment => "nested-comment",
+            ParseError::NoncharacterCharacterReference => "noncharacter-character-reference",
+            ParseError::NoncharacterInInputStream => "noncharacter-in-input-stream",
+            ParseError::NonVoidHtmlElementStartTagWithTrailingSolidus => {
+                "non-void-html-element-start-tag-with-trailing-solidus"
+            }
+            ParseError::NullCharacterReference => "null-character-reference",
+            ParseError::SurrogateCharacterReference => "surrogate-character-reference",
+            ParseError::SurrogateInInputStream => "surrogate-in-input-stream",
+            ParseError::UnexpectedCharacterAfterDoctypeSystemIdentifier => {
+                "unexpected-character-after-doctype-system-identifier"
+            }
+            ParseError::UnexpectedCharacterInAttributeName => "unexpected-character-in-attribute-name",
+            ParseError::UnexpectedCharacterInUnquotedAttributeValue => {
+                "unexpected-character-in-unquoted-attribute-value"
+            }
+            ParseError::UnexpectedEqualsSignBeforeAttributeName => {
+                "unexpected-equals-sign-before-attribute-name"
+            }
+            ParseError::UnexpectedNullCharacter => "unexpected-null-character",
+            ParseError::UnexpectedQuestionMarkInsteadOfTagName => {
+                "unexpected-question-mark-instead-of-tag-name"
+            }
+            ParseError::UnexpectedSolidusInTag => "unexpected-solidus-in-tag",
+            ParseError::UnknownNamedCharacterReference => "unknown-named-character-reference",
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -0,0 +1,149 @@
+use crate::error::ParseError;
+use crate::position::Position;
+
+// The input stream the tokenizer reads its characters from. The input is kept exactly the way it
+// was fed, so that offsets keep pointing into the original input, and gets preprocessed while it
+// is read instead: a CR or a CRLF pair reads as a single LF.
+pub(crate) struct InputStream {
+    // the input that has been fed but not consumed yet, plus a bit of what came before it.
+    buffer: String,
+    // offset of the first byte in buffer, since consumed input gets dropped from time to time.
+    start: usize,
+    // whether the end of the input is known, until then running out of input means waiting for
+    // more.
+    ended: bool,
+    // The insertion points of the scripts that are running, the innermost one last. Inserted text
+    // goes in just before the innermost one, and nothing after it can be read until it is popped:
+    // that is the rest of the page, which comes after whatever the script writes.
+    insertion_points: Vec<usize>,
+}
+
+impl InputStream {
+    pub(crate) fn new() -> Self {
+        InputStream {
+            buffer: String::new(),
+            start: 0,
+            ended: false,
+            insertion_points: Vec::new(),
+        }
+    }
+
+    pub(crate) fn push_str(&mut self, chunk: &str) {
+        debug_assert!(!self.ended, "input fed after end was called");
+        self.buffer.push_str(chunk);
+    }
+
+    pub(crate) fn end(&mut self) {
+        self.ended = true;
+    }
+
+    // whether nothing follows the readable input, there is always more after an insertion point.
+    pub(crate) fn is_ended(&self) -> bool {
+        self.ended && self.insertion_points.is_empty()
+    }
+
+    pub(crate) fn push_insertion_point(&mut self, offset: usize) {
+        self.insertion_points.push(offset);
+    }
+
+    pub(crate) fn pop_insertion_point(&mut self) {
+        self.insertion_points.pop();
+    }
+
+    pub(crate) fn has_insertion_point(&self) -> bool {
+        !self.insertion_points.is_empty()
+    }
+
+    // where inserted text goes.
+    pub(crate) fn insertion_point(&self) -> Option<usize> {
+        self.insertion_points.last().copied()
+    }
+
+    // puts text just before the innermost insertion point, which stays behind it so that the
+    // next insert goes after this one. The outer insertion points are further along and move too.
+    pub(crate) fn insert(&mut self, text: &str) {
+        let Some(&offset) = self.insertion_points.last() else {
+            return;
+        };
+        self.buffer.insert_str(offset - self.start, text);
+        for insertion_point in &mut self.insertion_points {
+            *insertion_point += text.len();
+        }
+    }
+
+    // drops the input before offset once that is at least half of the buffer, which keeps the
+    // copying linear.
+    pub(crate) fn discard_before(&mut self, offset: usize) {
+        let consumed = offset.saturating_sub(self.start);
+        if consumed > 0 && consumed >= self.buffer.len() / 2 {
+            self.buffer.drain(..consumed);
+            self.start += consumed;
+        }
+    }
+
+    // the buffered input and the offset of its first byte, which is what Text works with.
+    pub(crate) fn buffer(&self) -> (&str, usize) {
+        (&self.buffer, self.start)
+    }
+
+    // the unprocessed input from offset onwards, up to the insertion point if there is one.
+    pub(crate) fn from(&self, offset: usize) -> &str {
+        let end = self.insertion_points.last().map_or(self.buffer.len(), |end| end - self.start);
+        &self.buffer[offset - self.start..end]
+    }
+
+    pub(crate) fn slice(&self, start: usize, end: usize) -> &str {
+        &self.buffer[start - self.start..end - self.start]
+    }
+
+    // Whether reading at offset has to wait for more input. A CR at the end of what has been fed
+    // so far could still be the first half of a CRLF.
+    pub(crate) fn needs_input(&self, offset: usize) -> bool {
+        if self.is_ended() {
+            return false;
+        }
+        let rest = self.from(offset);
+        rest.is_empty() || rest == "\r"
+    }
+
+    // the preprocessed character at position and the position after it, None at the end of the
+    // input.
+    pub(crate) fn next_char(&self, position: Position) -> Option<(char, Position)> {
+        let rest = self.from(position.offset);
+        match rest.chars().next()? {
+            '\r' => {
+                let mut next = position.advance('\n');
+                if rest[1..].starts_with('\n') {
+                    next.offset += 1;
+                }
+                Some(('\n', next))
+            }
+            c => Some((c, position.advance(c))),
+        }
+    }
+}
+
+// The parse error for a character which is not allowed in the input stream, NULL is left to the
+// states which report it as unexpected-null-character. Surrogates have no
+// char to be in, they could only come from a decoder and those replace them with U+FFFD, so
+// surrogate-in-input-stream is left to whoever hands us input some other way.
+pub(crate) fn preprocessing_error(c: char) -> Option<ParseError> {
+    let code = c as u32;
+    if is_noncharacter(code) {
+        Some(ParseError::NoncharacterInInputStream)
+    } else if is_control(code)
+        && !matches!(c, '\u{0000}' | '\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{000D}' | '\u{0020}')
+    {
+        Some(ParseError::ControlCharacterInInputStream)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn is_noncharacter(code: u32) -> bool {
+    (0xFDD0..=0xFDEF).contains(&code) || (code & 0xFFFE) == 0xFFFE
+}
+
+pub(crate) fn is_control(code: u32) -> bool {
+    code <= 0x1F || (0x7F..=0x9F).contains(&code)
+}
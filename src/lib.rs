@@ -0,0 +1,35 @@
+mod attribute;
+mod decoder;
+mod encoding;
+mod encoding_indexes;
+mod entities;
+mod error;
+mod input;
+mod limits;
+mod local_name;
+mod local_names;
+mod lossless;
+mod options;
+mod position;
+mod scan;
+mod sink;
+mod state;
+mod template;
+mod text;
+mod token;
+mod tokenizer;
+
+pub use attribute::{Attribute, Namespace, QualName};
+pub use decoder::Decoder;
+pub use encoding::{Confidence, Encoding};
+pub use error::ParseError;
+pub use local_name::LocalName;
+pub use limits::{Limit, LimitBehavior, LimitExceeded, Limits};
+pub use lossless::{LosslessToken, LosslessTokenizer, Quote, RawAttribute, RawTag, RawValue};
+pub use options::TokenizerOptions;
+pub use position::{Position, Span};
+pub use sink::{RawKind, SinkResult, TokenSink};
+pub use state::State;
+pub use template::TemplateDelimiter;
+pub use token::{DocTypeIdentifier, Token, TokenType};
+pub use tokenizer::{BorrowedTokenizer, Tokenizer, TokenizerResult};
@@ -0,0 +1,246 @@
+use std::fmt;
+
+// Caps on how much the tokenizer builds up for a single thing, so that hostile input can not make
+// it use an unbounded amount of memory. Nothing is limited by default. Lengths are in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Limits {
+    // Truncate reads the input up to the limit, Drop leaves out the whole chunk that goes over
+    // it, and either way nothing fed after that is read. Tokens that were already handed out can
+    // not be taken back. Only input given to feed afterwards is checked.
+    pub input_size: Option<Limit>,
+    // Truncate keeps the first ones, Drop drops the tag.
+    pub attributes_per_tag: Option<Limit>,
+    // for tag names and attribute names, Drop drops the tag or the attribute.
+    pub name_length: Option<Limit>,
+    // Drop drops the attribute.
+    pub attribute_value_length: Option<Limit>,
+    // also for processing instructions and templates in text, see TemplateDelimiter.
+    pub comment_length: Option<Limit>,
+    // for the name and each of the identifiers on their own.
+    pub doctype_length: Option<Limit>,
+    // How deep start tags nest, counting every start tag until its end tag, except for void
+    // elements like <br> and self-closing tags in foreign content. Without a tree the tokenizer
+    // can't tell which elements the tree builder closes on its own, like a <p> before the next
+    // one, so this counts more than the tree has and wants some room. Truncate leaves out the
+    // tags that go deeper along with their end tags, Drop everything in them too.
+    pub tree_depth: Option<Limit>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limit {
+    pub max: usize,
+    pub behavior: LimitBehavior,
+}
+
+impl Limit {
+    pub fn new(max: usize, behavior: LimitBehavior) -> Self {
+        Limit { max, behavior }
+    }
+}
+
+// what happens to something that goes over its limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimitBehavior {
+    // keep as much as fits and ignore the rest.
+    Truncate,
+    // leave out the whole attribute or token.
+    Drop,
+    // stop tokenizing, see LimitExceeded.
+    Fail,
+}
+
+// The limit that made the tokenizer stop. Tokens emitted before it are still handed out, after
+// them there is no EOF token, and TokenizerResult::LimitExceeded is returned instead of Done.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LimitExceeded {
+    InputSize,
+    AttributesPerTag,
+    NameLength,
+    AttributeValueLength,
+    CommentLength,
+    DoctypeLength,
+    TreeDepth,
+}
+
+impl fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let limit = match self {
+            LimitExceeded::InputSize => "input size",
+            LimitExceeded::AttributesPerTag => "number of attributes per tag",
+            LimitExceeded::NameLength => "name length",
+            LimitExceeded::AttributeValueLength => "attribute value length",
+            LimitExceeded::CommentLength => "comment length",
+            LimitExceeded::DoctypeLength => "doctype length",
+            LimitExceeded::TreeDepth => "tree depth",
+        };
+        write!(f, "{} limit exceeded", limit)
+    }
+}
+
+impl std::error::Error for LimitExceeded {}
+
+// the longest start of text that is at most len bytes long.
+pub(crate) fn truncate(text: &str, len: usize) -> &str {
+    if len >= text.len() {
+        return text;
+    }
+    let mut end = len;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DocTypeIdentifier, Limit, LimitBehavior, LimitExceeded, Limits, TokenType, Tokenizer};
+
+    use LimitBehavior::{Drop, Fail, Truncate};
+
+    // the tokens of input fed in chunks, written out as markup to keep the expectations short,
+    // and the limit that made the tokenizer stop.
+    fn tokenize(chunks: &[&str], limits: Limits) -> (String, Option<LimitExceeded>) {
+        let mut tokenizer = Tokenizer::streaming();
+        tokenizer.set_limits(limits);
+        let mut tokens = Vec::new();
+        for chunk in chunks {
+            tokenizer.feed(chunk);
+            tokens.extend(tokenizer.by_ref().map(|token| show(&token)));
+        }
+        tokenizer.end();
+        tokens.extend(tokenizer.by_ref().map(|token| show(&token)));
+        (tokens.join("|"), tokenizer.limit_exceeded())
+    }
+
+    fn show(token: &TokenType) -> String {
+        let identifier = |identifier: &DocTypeIdentifier| match identifier {
+            DocTypeIdentifier::Missing => String::new(),
+            DocTypeIdentifier::Available { id } => format!(" {:?}", id),
+        };
+        match token {
+            TokenType::DocType { name, public_identifier, system_identifier, .. } => format!(
+                "<!DOCTYPE {}{}{}>",
+                name.as_deref().unwrap_or(""),
+                identifier(public_identifier),
+                identifier(system_identifier)
+            ),
+            TokenType::StartTag { tag_name, attributes, .. } => {
+                let attributes: String = attributes
+                    .iter()
+                    .map(|attribute| format!(" {}={:?}", attribute.name.local, attribute.value))
+                    .collect();
+                format!("<{}{}>", tag_name, attributes)
+            }
+            TokenType::EndTag { tag_name, .. } => format!("</{}>", tag_name),
+            TokenType::Comment { data } => format!("<!--{}-->", data),
+            TokenType::ProcessingInstruction { target, data } => format!("<?{} {}?>", target, data),
+            TokenType::Character { data } | TokenType::Template { data } => data.clone(),
+            TokenType::EOF => "EOF".to_string(),
+        }
+    }
+
+    fn limit(max: usize, behavior: LimitBehavior) -> Option<Limit> {
+        Some(Limit::new(max, behavior))
+    }
+
+    #[test]
+    fn input_size() {
+        let chunks = ["<p>hello", " world</p>"];
+        let limits = |behavior| Limits { input_size: limit(10, behavior), ..Limits::default() };
+        assert_eq!(tokenize(&chunks, limits(Truncate)), ("<p>|hello w|EOF".to_string(), None));
+        // the whole chunk that goes over is left out.
+        assert_eq!(tokenize(&chunks, limits(Drop)), ("<p>|hello|EOF".to_string(), None));
+        assert_eq!(tokenize(&["<p>hello world</p>"], limits(Drop)), ("EOF".to_string(), None));
+        // what the first chunk had is still handed out, the second one goes over.
+        assert_eq!(
+            tokenize(&chunks, limits(Fail)),
+            ("<p>|hello".to_string(), Some(LimitExceeded::InputSize))
+        );
+        assert_eq!(tokenize(&["<p>hello w"], limits(Fail)), ("<p>|hello w|EOF".to_string(), None));
+    }
+
+    #[test]
+    fn attributes_per_tag() {
+        let chunks = ["<a b=1 c=2 d=3>x<a b=1>"];
+        let limits = |behavior| Limits { attributes_per_tag: limit(2, behavior), ..Limits::default() };
+        assert_eq!(tokenize(&chunks, limits(Truncate)), (r#"<a b="1" c="2">|x|<a b="1">|EOF"#.to_string(), None));
+        assert_eq!(tokenize(&chunks, limits(Drop)), (r#"x|<a b="1">|EOF"#.to_string(), None));
+        assert_eq!(tokenize(&chunks, limits(Fail)), (String::new(), Some(LimitExceeded::AttributesPerTag)));
+    }
+
+    #[test]
+    fn name_length() {
+        let limits = |behavior| Limits { name_length: limit(3, behavior), ..Limits::default() };
+        let chunks = ["<abcd x=1>x<a wxyz=1 y=2>"];
+        assert_eq!(
+            tokenize(&chunks, limits(Truncate)),
+            (r#"<abc x="1">|x|<a wxy="1" y="2">|EOF"#.to_string(), None)
+        );
+        // a tag name drops the tag, an attribute name only the attribute.
+        assert_eq!(tokenize(&chunks, limits(Drop)), (r#"x|<a y="2">|EOF"#.to_string(), None));
+        assert_eq!(tokenize(&chunks, limits(Fail)), (String::new(), Some(LimitExceeded::NameLength)));
+        assert_eq!(
+            tokenize(&["x<a wxyz=1>"], limits(Fail)),
+            ("x".to_string(), Some(LimitExceeded::NameLength))
+        );
+    }
+
+    #[test]
+    fn attribute_value_length() {
+        let limits = |behavior| Limits { attribute_value_length: limit(3, behavior), ..Limits::default() };
+        let chunks = ["<a x=abcd y=\"a&amp;b\">"];
+        assert_eq!(tokenize(&chunks, limits(Truncate)), (r#"<a x="abc" y="a&b">|EOF"#.to_string(), None));
+        assert_eq!(tokenize(&chunks, limits(Drop)), (r#"<a y="a&b">|EOF"#.to_string(), None));
+        assert_eq!(tokenize(&chunks, limits(Fail)), (String::new(), Some(LimitExceeded::AttributeValueLength)));
+    }
+
+    #[test]
+    fn comment_length() {
+        let limits = |behavior| Limits { comment_length: limit(3, behavior), ..Limits::default() };
+        let chunks = ["<!--abcd-->x<!--ab--><?abcd>"];
+        assert_eq!(
+            tokenize(&chunks, limits(Truncate)),
+            ("<!--abc-->|x|<!--ab-->|<!--?ab-->|EOF".to_string(), None)
+        );
+        assert_eq!(tokenize(&chunks, limits(Drop)), ("x|<!--ab-->|EOF".to_string(), None));
+        assert_eq!(tokenize(&chunks, limits(Fail)), (String::new(), Some(LimitExceeded::CommentLength)));
+    }
+
+    #[test]
+    fn doctype_length() {
+        let limits = |behavior| Limits { doctype_length: limit(4, behavior), ..Limits::default() };
+        // each of the name and identifiers is checked on its own.
+        let chunks = ["<!DOCTYPE htmlx PUBLIC \"abcd\" \"abcde\">x"];
+        assert_eq!(
+            tokenize(&chunks, limits(Truncate)),
+            (r#"<!DOCTYPE html "abcd" "abcd">|x|EOF"#.to_string(), None)
+        );
+        assert_eq!(tokenize(&chunks, limits(Drop)), ("x|EOF".to_string(), None));
+        assert_eq!(tokenize(&chunks, limits(Fail)), (String::new(), Some(LimitExceeded::DoctypeLength)));
+    }
+
+    #[test]
+    fn tree_depth() {
+        let limits = |behavior| Limits { tree_depth: limit(2, behavior), ..Limits::default() };
+        let chunks = ["<a>a<b>b<br><c>c<d>d</d><img></c>e</b>f<b>g</b></a>h"];
+        assert_eq!(
+            tokenize(&chunks, limits(Truncate)),
+            ("<a>|a|<b>|b|c|d|e|</b>|f|<b>|g|</b>|</a>|h|EOF".to_string(), None)
+        );
+        assert_eq!(
+            tokenize(&chunks, limits(Drop)),
+            ("<a>|a|<b>|b|e|</b>|f|<b>|g|</b>|</a>|h|EOF".to_string(), None)
+        );
+        assert_eq!(tokenize(&chunks, limits(Fail)), ("<a>|a|<b>|b".to_string(), Some(LimitExceeded::TreeDepth)));
+        // <br> is void, and it is only in foreign content that a self-closing tag is closed.
+        assert_eq!(tokenize(&["<a><br><b>"], limits(Fail)), ("<a>|<br>|<b>|EOF".to_string(), None));
+        assert_eq!(tokenize(&["<a><b/><c>"], limits(Fail)), ("<a>|<b>".to_string(), Some(LimitExceeded::TreeDepth)));
+        let mut tokenizer = Tokenizer::streaming();
+        tokenizer.set_limits(limits(Fail));
+        tokenizer.set_in_foreign_content(true);
+        tokenizer.feed("<a><b/><c/><d></d></a>");
+        tokenizer.end();
+        assert_eq!(tokenizer.by_ref().count(), 7);
+        assert_eq!(tokenizer.limit_exceeded(), None);
+    }
+}
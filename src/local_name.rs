@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::ops::Deref;
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, PoisonError, Weak};
+
+use crate::local_names::STATIC_LOCAL_NAMES;
+
+// An interned element or attribute name. The names HTML, SVG and MathML define are an index into
+// a static table and come with a constant each (LocalName::TABLE), any other name is shared
+// through a global set. Either way there is only ever one atom per name, so comparing two of
+// them is comparing integers and never looks at the text.
+#[derive(Clone)]
+pub struct LocalName(Atom);
+
+#[derive(Clone)]
+enum Atom {
+    Static(u32),
+    Dynamic(Arc<str>),
+}
+
+impl LocalName {
+    pub(crate) const fn from_index(index: u32) -> Self {
+        LocalName(Atom::Static(index))
+    }
+
+    pub fn new(name: &str) -> Self {
+        match STATIC_LOCAL_NAMES.binary_search(&name) {
+            Ok(index) => LocalName(Atom::Static(index as u32)),
+            Err(_) => LocalName(Atom::Dynamic(intern(name))),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            Atom::Static(index) => STATIC_LOCAL_NAMES[*index as usize],
+            Atom::Dynamic(name) => name,
+        }
+    }
+
+    // whether this is one of the names with a constant.
+    pub fn is_static(&self) -> bool {
+        matches!(self.0, Atom::Static(_))
+    }
+}
+
+impl PartialEq for LocalName {
+    fn eq(&self, other: &LocalName) -> bool {
+        match (&self.0, &other.0) {
+            (Atom::Static(a), Atom::Static(b)) => a == b,
+            (Atom::Dynamic(a), Atom::Dynamic(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for LocalName {}
+
+impl Hash for LocalName {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match &self.0 {
+            Atom::Static(index) => index.hash(state),
+            Atom::Dynamic(name) => Arc::as_ptr(name).cast::<u8>().hash(state),
+        }
+    }
+}
+
+impl PartialEq<str> for LocalName {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for LocalName {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Deref for LocalName {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<&str> for LocalName {
+    fn from(name: &str) -> Self {
+        LocalName::new(name)
+    }
+}
+
+impl fmt::Display for LocalName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+// shows the name the way a String would, which keeps tokens readable.
+impl fmt::Debug for LocalName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+// The names which are not in the static table, split over shards by the hash of the name so
+// that tokenizers on different threads rarely wait for the same lock. Atoms that are not used
+// anymore get swept out of a shard whenever it has doubled since its last sweep, so that a long
+// running process does not hold on to every unknown name it has ever seen.
+struct Interner {
+    names: HashMap<Box<str>, Weak<str>>,
+    sweep_at: usize,
+}
+
+const SHARDS: usize = 16;
+const MIN_SWEEP_AT: usize = 16;
+
+static INTERNER: OnceLock<[Mutex<Interner>; SHARDS]> = OnceLock::new();
+
+// the shard of a name, with the lock taken.
+fn shard(name: &str) -> MutexGuard<'static, Interner> {
+    let shards = INTERNER.get_or_init(|| {
+        std::array::from_fn(|_| {
+            Mutex::new(Interner {
+                names: HashMap::new(),
+                sweep_at: MIN_SWEEP_AT,
+            })
+        })
+    });
+    // nothing can be left half done while a lock is held, so a poisoned one is still fine.
+    shards[shard_index(name)].lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn shard_index(name: &str) -> usize {
+    let mut hasher = DefaultHasher::new();
+    name.hash(&mut hasher);
+    hasher.finish() as usize % SHARDS
+}
+
+fn intern(name: &str) -> Arc<str> {
+    let mut interner = shard(name);
+    if let Some(atom) = interner.names.get(name).and_then(Weak::upgrade) {
+        return atom;
+    }
+    if interner.names.len() >= interner.sweep_at {
+        interner.names.retain(|_, atom| atom.strong_count() > 0);
+        interner.sweep_at = (interner.names.len() * 2).max(MIN_SWEEP_AT);
+    }
+    let atom: Arc<str> = Arc::from(name);
+    interner.names.insert(name.into(), Arc::downgrade(&atom));
+    atom
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LocalName, shard, shard_index};
+
+    fn is_interned(name: &str) -> bool {
+        shard(name).names.contains_key(name)
+    }
+
+    #[test]
+    fn static_names() {
+        assert_eq!(LocalName::new("div"), LocalName::DIV);
+        assert!(LocalName::new("div").is_static());
+        assert_eq!(LocalName::DIV, "div");
+        // the table is case-sensitive, the tokenizer lowercases names before they get here.
+        assert!(!LocalName::new("DIV").is_static());
+        assert_ne!(LocalName::new("DIV"), LocalName::DIV);
+        assert!(!is_interned("div"));
+    }
+
+    #[test]
+    fn dynamic_names() {
+        let name = LocalName::new("x-dynamic-name");
+        assert!(!name.is_static());
+        assert_eq!(name, LocalName::new("x-dynamic-name"));
+        assert_eq!(name, "x-dynamic-name");
+        assert_ne!(name, LocalName::new("x-other-dynamic-name"));
+        // a dynamic name never equals a static one, the static table is checked first.
+        assert_ne!(name, LocalName::DIV);
+    }
+
+    #[test]
+    fn unused_names_are_swept() {
+        let name = "x-unused-name";
+        drop(LocalName::new(name));
+        assert!(is_interned(name));
+
+        // filling the shard of the name sweeps it at least once.
+        let kept: Vec<LocalName> = (0..)
+            .map(|i| format!("x-kept-name-{}", i))
+            .filter(|kept| shard_index(kept) == shard_index(name))
+            .take(100)
+            .map(|kept| LocalName::new(&kept))
+            .collect();
+        assert!(!is_interned(name));
+        assert!(kept.iter().all(|kept| is_interned(kept)));
+        // and it comes back when used again.
+        assert_eq!(LocalName::new(name), LocalName::new(name));
+    }
+}
@@ -0,0 +1,2283 @@
+use crate::local_name::LocalName;
+
+// Every element and attribute name defined by HTML, SVG and MathML, sorted so that names can be
+// looked up with a binary search. Names that the tree builder puts in camel case are here as the
+// lowercase names the tokenizer produces too, and the prefixed foreign attributes both as the
+// tokenizer produces them and as their local name.
+//
+// Each has a constant named after it in upper case, with "-", ":" and the humps of camel case
+// turned into "_". clipPath would clash with clip-path, it is CLIP_PATH_ELEMENT.
+
+pub(crate) static STATIC_LOCAL_NAMES: &[&str] = &[
+    "a",
+    "abbr",
+    "abs",
+    "accent",
+    "accent-height",
+    "accentunder",
+    "accept",
+    "accept-charset",
+    "accesskey",
+    "accumulate",
+    "acronym",
+    "action",
+    "actiontype",
+    "actuate",
+    "additive",
+    "address",
+    "align",
+    "alignment-baseline",
+    "alignmentscope",
+    "alink",
+    "allow",
+    "allowfullscreen",
+    "alphabetic",
+    "alt",
+    "altGlyph",
+    "altGlyphDef",
+    "altGlyphItem",
+    "altglyph",
+    "altglyphdef",
+    "altglyphitem",
+    "altimg",
+    "altimg-height",
+    "altimg-valign",
+    "altimg-width",
+    "alttext",
+    "amplitude",
+    "and",
+    "animate",
+    "animateColor",
+    "animateMotion",
+    "animateTransform",
+    "animatecolor",
+    "animatemotion",
+    "animatetransform",
+    "annotation",
+    "annotation-xml",
+    "applet",
+    "apply",
+    "approx",
+    "arabic-form",
+    "arccos",
+    "arccosh",
+    "arccot",
+    "arccoth",
+    "arccsc",
+    "arccsch",
+    "archive",
+    "arcrole",
+    "arcsec",
+    "arcsech",
+    "arcsin",
+    "arcsinh",
+    "arctan",
+    "arctanh",
+    "area",
+    "arg",
+    "aria-activedescendant",
+    "aria-atomic",
+    "aria-autocomplete",
+    "aria-braillelabel",
+    "aria-brailleroledescription",
+    "aria-busy",
+    "aria-checked",
+    "aria-colcount",
+    "aria-colindex",
+    "aria-colindextext",
+    "aria-colspan",
+    "aria-controls",
+    "aria-current",
+    "aria-describedby",
+    "aria-description",
+    "aria-details",
+    "aria-disabled",
+    "aria-errormessage",
+    "aria-expanded",
+    "aria-flowto",
+    "aria-haspopup",
+    "aria-hidden",
+    "aria-invalid",
+    "aria-keyshortcuts",
+    "aria-label",
+    "aria-labelledby",
+    "aria-level",
+    "aria-live",
+    "aria-modal",
+    "aria-multiline",
+    "aria-multiselectable",
+    "aria-orientation",
+    "aria-owns",
+    "aria-placeholder",
+    "aria-posinset",
+    "aria-pressed",
+    "aria-readonly",
+    "aria-relevant",
+    "aria-required",
+    "aria-roledescription",
+    "aria-rowcount",
+    "aria-rowindex",
+    "aria-rowindextext",
+    "aria-rowspan",
+    "aria-selected",
+    "aria-setsize",
+    "aria-sort",
+    "aria-valuemax",
+    "aria-valuemin",
+    "aria-valuenow",
+    "aria-valuetext",
+    "article",
+    "ascent",
+    "aside",
+    "async",
+    "attributeName",
+    "attributeType",
+    "attributename",
+    "attributetype",
+    "audio",
+    "autocapitalize",
+    "autocomplete",
+    "autocorrect",
+    "autofocus",
+    "autoplay",
+    "axis",
+    "azimuth",
+    "b",
+    "background",
+    "base",
+    "baseFrequency",
+    "baseProfile",
+    "basefont",
+    "basefrequency",
+    "baseline-shift",
+    "baseprofile",
+    "bbox",
+    "bdi",
+    "bdo",
+    "begin",
+    "bevelled",
+    "bgcolor",
+    "bgsound",
+    "bias",
+    "big",
+    "bind",
+    "blink",
+    "blocking",
+    "blockquote",
+    "body",
+    "border",
+    "br",
+    "button",
+    "bvar",
+    "by",
+    "calcMode",
+    "calcmode",
+    "canvas",
+    "cap-height",
+    "caption",
+    "card",
+    "cartesianproduct",
+    "cbytes",
+    "cd",
+    "cdgroup",
+    "ceiling",
+    "cellpadding",
+    "cellspacing",
+    "center",
+    "cerror",
+    "char",
+    "charalign",
+    "charoff",
+    "charset",
+    "checked",
+    "ci",
+    "circle",
+    "cite",
+    "class",
+    "classid",
+    "clear",
+    "clip",
+    "clip-path",
+    "clip-rule",
+    "clipPath",
+    "clipPathUnits",
+    "clippath",
+    "clippathunits",
+    "close",
+    "closure",
+    "cn",
+    "code",
+    "codebase",
+    "codetype",
+    "codomain",
+    "col",
+    "colgroup",
+    "color",
+    "color-interpolation",
+    "color-interpolation-filters",
+    "color-profile",
+    "color-rendering",
+    "cols",
+    "colspan",
+    "columnalign",
+    "columnlines",
+    "columnspacing",
+    "columnspan",
+    "columnwidth",
+    "compact",
+    "complexes",
+    "compose",
+    "condition",
+    "conjugate",
+    "content",
+    "contentScriptType",
+    "contentStyleType",
+    "contenteditable",
+    "contentscripttype",
+    "contentstyletype",
+    "controls",
+    "coords",
+    "cos",
+    "cosh",
+    "cot",
+    "coth",
+    "crossorigin",
+    "crossout",
+    "cs",
+    "csc",
+    "csch",
+    "csymbol",
+    "curl",
+    "cursor",
+    "cx",
+    "cy",
+    "d",
+    "data",
+    "datalist",
+    "datetime",
+    "dd",
+    "decelerate",
+    "decimalpoint",
+    "declare",
+    "decoding",
+    "default",
+    "defer",
+    "definitionURL",
+    "definitionurl",
+    "defs",
+    "degree",
+    "del",
+    "denomalign",
+    "depth",
+    "desc",
+    "descent",
+    "details",
+    "determinant",
+    "dfn",
+    "dialog",
+    "diff",
+    "diffuseConstant",
+    "diffuseconstant",
+    "dir",
+    "direction",
+    "dirname",
+    "disabled",
+    "discard",
+    "display",
+    "displaystyle",
+    "div",
+    "divergence",
+    "divide",
+    "divisor",
+    "dl",
+    "domain",
+    "domainofapplication",
+    "dominant-baseline",
+    "download",
+    "draggable",
+    "dt",
+    "dur",
+    "dx",
+    "dy",
+    "edge",
+    "edgeMode",
+    "edgemode",
+    "elevation",
+    "ellipse",
+    "em",
+    "embed",
+    "emptyset",
+    "enable-background",
+    "encoding",
+    "enctype",
+    "end",
+    "enterkeyhint",
+    "eq",
+    "equalcolumns",
+    "equalrows",
+    "equivalent",
+    "eulergamma",
+    "exists",
+    "exp",
+    "exponent",
+    "exponentiale",
+    "externalResourcesRequired",
+    "externalresourcesrequired",
+    "face",
+    "factorial",
+    "factorof",
+    "false",
+    "feBlend",
+    "feColorMatrix",
+    "feComponentTransfer",
+    "feComposite",
+    "feConvolveMatrix",
+    "feDiffuseLighting",
+    "feDisplacementMap",
+    "feDistantLight",
+    "feDropShadow",
+    "feFlood",
+    "feFuncA",
+    "feFuncB",
+    "feFuncG",
+    "feFuncR",
+    "feGaussianBlur",
+    "feImage",
+    "feMerge",
+    "feMergeNode",
+    "feMorphology",
+    "feOffset",
+    "fePointLight",
+    "feSpecularLighting",
+    "feSpotLight",
+    "feTile",
+    "feTurbulence",
+    "feblend",
+    "fecolormatrix",
+    "fecomponenttransfer",
+    "fecomposite",
+    "feconvolvematrix",
+    "fediffuselighting",
+    "fedisplacementmap",
+    "fedistantlight",
+    "fedropshadow",
+    "feflood",
+    "fefunca",
+    "fefuncb",
+    "fefuncg",
+    "fefuncr",
+    "fegaussianblur",
+    "feimage",
+    "femerge",
+    "femergenode",
+    "femorphology",
+    "fence",
+    "feoffset",
+    "fepointlight",
+    "fespecularlighting",
+    "fespotlight",
+    "fetchpriority",
+    "fetile",
+    "feturbulence",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "fill",
+    "fill-opacity",
+    "fill-rule",
+    "filter",
+    "filterRes",
+    "filterUnits",
+    "filterres",
+    "filterunits",
+    "flood-color",
+    "flood-opacity",
+    "floor",
+    "fn",
+    "font",
+    "font-face",
+    "font-face-format",
+    "font-face-name",
+    "font-face-src",
+    "font-face-uri",
+    "font-family",
+    "font-size",
+    "font-size-adjust",
+    "font-stretch",
+    "font-style",
+    "font-variant",
+    "font-weight",
+    "fontfamily",
+    "fontsize",
+    "fontstyle",
+    "fontweight",
+    "footer",
+    "for",
+    "forall",
+    "foreignObject",
+    "foreignobject",
+    "form",
+    "formaction",
+    "format",
+    "formenctype",
+    "formmethod",
+    "formnovalidate",
+    "formtarget",
+    "fr",
+    "frame",
+    "frameborder",
+    "frameset",
+    "framespacing",
+    "from",
+    "fx",
+    "fy",
+    "g",
+    "g1",
+    "g2",
+    "gcd",
+    "geq",
+    "glyph",
+    "glyph-name",
+    "glyph-orientation-horizontal",
+    "glyph-orientation-vertical",
+    "glyphRef",
+    "glyphref",
+    "grad",
+    "gradientTransform",
+    "gradientUnits",
+    "gradienttransform",
+    "gradientunits",
+    "groupalign",
+    "gt",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "hanging",
+    "head",
+    "header",
+    "headers",
+    "height",
+    "hgroup",
+    "hidden",
+    "high",
+    "hkern",
+    "horiz-adv-x",
+    "horiz-origin-x",
+    "hr",
+    "href",
+    "hreflang",
+    "hspace",
+    "html",
+    "http-equiv",
+    "i",
+    "id",
+    "ident",
+    "ideographic",
+    "iframe",
+    "image",
+    "image-rendering",
+    "imagesizes",
+    "imagesrcset",
+    "imaginary",
+    "imaginaryi",
+    "img",
+    "implies",
+    "in",
+    "in2",
+    "indentalign",
+    "indentalignfirst",
+    "indentalignlast",
+    "indentshift",
+    "indentshiftfirst",
+    "indentshiftlast",
+    "indenttarget",
+    "inert",
+    "infinity",
+    "infixlinebreakstyle",
+    "input",
+    "inputmode",
+    "ins",
+    "int",
+    "integers",
+    "integrity",
+    "intercept",
+    "intersect",
+    "interval",
+    "inverse",
+    "is",
+    "isindex",
+    "ismap",
+    "itemid",
+    "itemprop",
+    "itemref",
+    "itemscope",
+    "itemtype",
+    "k",
+    "k1",
+    "k2",
+    "k3",
+    "k4",
+    "kbd",
+    "kernelMatrix",
+    "kernelUnitLength",
+    "kernelmatrix",
+    "kernelunitlength",
+    "kerning",
+    "keyPoints",
+    "keySplines",
+    "keyTimes",
+    "keygen",
+    "keypoints",
+    "keysplines",
+    "keytimes",
+    "kind",
+    "label",
+    "lambda",
+    "lang",
+    "language",
+    "laplacian",
+    "largeop",
+    "lcm",
+    "legend",
+    "length",
+    "lengthAdjust",
+    "lengthadjust",
+    "leq",
+    "letter-spacing",
+    "li",
+    "lighting-color",
+    "limit",
+    "limitingConeAngle",
+    "limitingconeangle",
+    "line",
+    "linearGradient",
+    "lineargradient",
+    "linebreak",
+    "linebreakmultchar",
+    "linebreakstyle",
+    "lineleading",
+    "linethickness",
+    "link",
+    "list",
+    "listing",
+    "ln",
+    "loading",
+    "local",
+    "location",
+    "log",
+    "logbase",
+    "longdesc",
+    "longdivstyle",
+    "loop",
+    "low",
+    "lowlimit",
+    "lquote",
+    "lspace",
+    "lt",
+    "macros",
+    "maction",
+    "main",
+    "maligngroup",
+    "malignmark",
+    "manifest",
+    "map",
+    "marginheight",
+    "marginwidth",
+    "mark",
+    "marker",
+    "marker-end",
+    "marker-mid",
+    "marker-start",
+    "markerHeight",
+    "markerUnits",
+    "markerWidth",
+    "markerheight",
+    "markerunits",
+    "markerwidth",
+    "marquee",
+    "mask",
+    "maskContentUnits",
+    "maskUnits",
+    "maskcontentunits",
+    "maskunits",
+    "math",
+    "mathbackground",
+    "mathcolor",
+    "mathematical",
+    "mathsize",
+    "mathvariant",
+    "matrix",
+    "matrixrow",
+    "max",
+    "maxlength",
+    "maxsize",
+    "maxwidth",
+    "mean",
+    "media",
+    "median",
+    "menclose",
+    "menu",
+    "menuitem",
+    "merror",
+    "meta",
+    "metadata",
+    "meter",
+    "method",
+    "mfenced",
+    "mfrac",
+    "mglyph",
+    "mi",
+    "min",
+    "minlabelspacing",
+    "minlength",
+    "minsize",
+    "minus",
+    "missing-glyph",
+    "mlabeledtr",
+    "mlongdiv",
+    "mmultiscripts",
+    "mn",
+    "mo",
+    "mode",
+    "moment",
+    "momentabout",
+    "movablelimits",
+    "mover",
+    "mpadded",
+    "mpath",
+    "mphantom",
+    "mprescripts",
+    "mroot",
+    "mrow",
+    "ms",
+    "mscarries",
+    "mscarry",
+    "msgroup",
+    "msline",
+    "mspace",
+    "msqrt",
+    "msrow",
+    "mstack",
+    "mstyle",
+    "msub",
+    "msubsup",
+    "msup",
+    "mtable",
+    "mtd",
+    "mtext",
+    "mtr",
+    "multicol",
+    "multiple",
+    "munder",
+    "munderover",
+    "muted",
+    "name",
+    "nargs",
+    "nav",
+    "neq",
+    "nextid",
+    "nobr",
+    "noembed",
+    "noframes",
+    "nohref",
+    "nomodule",
+    "nonce",
+    "none",
+    "noresize",
+    "noscript",
+    "noshade",
+    "not",
+    "notanumber",
+    "notation",
+    "notin",
+    "notprsubset",
+    "notsubset",
+    "novalidate",
+    "nowrap",
+    "numOctaves",
+    "numalign",
+    "numoctaves",
+    "object",
+    "occurrence",
+    "offset",
+    "ol",
+    "onabort",
+    "onafterprint",
+    "onauxclick",
+    "onbeforeinput",
+    "onbeforematch",
+    "onbeforeprint",
+    "onbeforetoggle",
+    "onbeforeunload",
+    "onblur",
+    "oncancel",
+    "oncanplay",
+    "oncanplaythrough",
+    "onchange",
+    "onclick",
+    "onclose",
+    "oncontextlost",
+    "oncontextmenu",
+    "oncontextrestored",
+    "oncopy",
+    "oncuechange",
+    "oncut",
+    "ondblclick",
+    "ondrag",
+    "ondragend",
+    "ondragenter",
+    "ondragleave",
+    "ondragover",
+    "ondragstart",
+    "ondrop",
+    "ondurationchange",
+    "onemptied",
+    "onended",
+    "onerror",
+    "onfocus",
+    "onformdata",
+    "onhashchange",
+    "oninput",
+    "oninvalid",
+    "onkeydown",
+    "onkeypress",
+    "onkeyup",
+    "onlanguagechange",
+    "onload",
+    "onloadeddata",
+    "onloadedmetadata",
+    "onloadstart",
+    "onmessage",
+    "onmessageerror",
+    "onmousedown",
+    "onmouseenter",
+    "onmouseleave",
+    "onmousemove",
+    "onmouseout",
+    "onmouseover",
+    "onmouseup",
+    "onoffline",
+    "ononline",
+    "onpagehide",
+    "onpagereveal",
+    "onpageshow",
+    "onpageswap",
+    "onpaste",
+    "onpause",
+    "onplay",
+    "onplaying",
+    "onpopstate",
+    "onprogress",
+    "onratechange",
+    "onrejectionhandled",
+    "onreset",
+    "onresize",
+    "onscroll",
+    "onscrollend",
+    "onsecuritypolicyviolation",
+    "onseeked",
+    "onseeking",
+    "onselect",
+    "onslotchange",
+    "onstalled",
+    "onstorage",
+    "onsubmit",
+    "onsuspend",
+    "ontimeupdate",
+    "ontoggle",
+    "onunhandledrejection",
+    "onunload",
+    "onvolumechange",
+    "onwaiting",
+    "onwheel",
+    "opacity",
+    "open",
+    "operator",
+    "optgroup",
+    "optimum",
+    "option",
+    "or",
+    "order",
+    "orient",
+    "orientation",
+    "origin",
+    "other",
+    "otherwise",
+    "outerproduct",
+    "output",
+    "overflow",
+    "overline-position",
+    "overline-thickness",
+    "p",
+    "paint-order",
+    "panose-1",
+    "param",
+    "partialdiff",
+    "path",
+    "pathLength",
+    "pathlength",
+    "pattern",
+    "patternContentUnits",
+    "patternTransform",
+    "patternUnits",
+    "patterncontentunits",
+    "patterntransform",
+    "patternunits",
+    "pi",
+    "picture",
+    "piece",
+    "piecewise",
+    "ping",
+    "placeholder",
+    "plaintext",
+    "playsinline",
+    "plus",
+    "pointer-events",
+    "points",
+    "pointsAtX",
+    "pointsAtY",
+    "pointsAtZ",
+    "pointsatx",
+    "pointsaty",
+    "pointsatz",
+    "polygon",
+    "polyline",
+    "popover",
+    "popovertarget",
+    "popovertargetaction",
+    "position",
+    "poster",
+    "power",
+    "pre",
+    "preload",
+    "preserveAlpha",
+    "preserveAspectRatio",
+    "preservealpha",
+    "preserveaspectratio",
+    "primes",
+    "primitiveUnits",
+    "primitiveunits",
+    "product",
+    "profile",
+    "progress",
+    "prompt",
+    "prsubset",
+    "q",
+    "quotient",
+    "r",
+    "radialGradient",
+    "radialgradient",
+    "radius",
+    "rationals",
+    "rb",
+    "readonly",
+    "real",
+    "reals",
+    "rect",
+    "refX",
+    "refY",
+    "referrerpolicy",
+    "refx",
+    "refy",
+    "rel",
+    "reln",
+    "rem",
+    "rendering-intent",
+    "repeatCount",
+    "repeatDur",
+    "repeatcount",
+    "repeatdur",
+    "required",
+    "requiredExtensions",
+    "requiredFeatures",
+    "requiredextensions",
+    "requiredfeatures",
+    "restart",
+    "result",
+    "rev",
+    "reversed",
+    "role",
+    "root",
+    "rotate",
+    "rowalign",
+    "rowlines",
+    "rows",
+    "rowspacing",
+    "rowspan",
+    "rp",
+    "rquote",
+    "rspace",
+    "rt",
+    "rtc",
+    "ruby",
+    "rules",
+    "rx",
+    "ry",
+    "s",
+    "samp",
+    "sandbox",
+    "scalarproduct",
+    "scale",
+    "scheme",
+    "scope",
+    "script",
+    "scriptlevel",
+    "scriptminsize",
+    "scriptsizemultiplier",
+    "scrolling",
+    "sdev",
+    "search",
+    "sec",
+    "sech",
+    "section",
+    "seed",
+    "select",
+    "selected",
+    "selection",
+    "selector",
+    "semantics",
+    "sep",
+    "separator",
+    "separators",
+    "set",
+    "setdiff",
+    "shadowrootclonable",
+    "shadowrootdelegatesfocus",
+    "shadowrootmode",
+    "shadowrootserializable",
+    "shape",
+    "shape-rendering",
+    "share",
+    "shift",
+    "show",
+    "side",
+    "sin",
+    "sinh",
+    "size",
+    "sizes",
+    "slope",
+    "slot",
+    "small",
+    "source",
+    "space",
+    "spacer",
+    "spacing",
+    "span",
+    "specularConstant",
+    "specularExponent",
+    "specularconstant",
+    "specularexponent",
+    "speed",
+    "spellcheck",
+    "spreadMethod",
+    "spreadmethod",
+    "src",
+    "srcdoc",
+    "srclang",
+    "srcset",
+    "stackalign",
+    "standby",
+    "start",
+    "startOffset",
+    "startoffset",
+    "stdDeviation",
+    "stddeviation",
+    "stemh",
+    "stemv",
+    "step",
+    "stitchTiles",
+    "stitchtiles",
+    "stop",
+    "stop-color",
+    "stop-opacity",
+    "stretchy",
+    "strike",
+    "strikethrough-position",
+    "strikethrough-thickness",
+    "string",
+    "stroke",
+    "stroke-dasharray",
+    "stroke-dashoffset",
+    "stroke-linecap",
+    "stroke-linejoin",
+    "stroke-miterlimit",
+    "stroke-opacity",
+    "stroke-width",
+    "strong",
+    "style",
+    "sub",
+    "subscriptshift",
+    "subset",
+    "sum",
+    "summary",
+    "sup",
+    "superscriptshift",
+    "surfaceScale",
+    "surfacescale",
+    "svg",
+    "switch",
+    "symbol",
+    "symmetric",
+    "systemLanguage",
+    "systemlanguage",
+    "tabindex",
+    "table",
+    "tableValues",
+    "tablevalues",
+    "tan",
+    "tanh",
+    "target",
+    "targetX",
+    "targetY",
+    "targetx",
+    "targety",
+    "tbody",
+    "td",
+    "template",
+    "tendsto",
+    "text",
+    "text-anchor",
+    "text-decoration",
+    "text-rendering",
+    "textLength",
+    "textPath",
+    "textarea",
+    "textlength",
+    "textpath",
+    "tfoot",
+    "th",
+    "thead",
+    "time",
+    "times",
+    "title",
+    "to",
+    "tr",
+    "track",
+    "transform",
+    "transform-origin",
+    "translate",
+    "transpose",
+    "tref",
+    "true",
+    "tspan",
+    "tt",
+    "type",
+    "u",
+    "u1",
+    "u2",
+    "ul",
+    "underline-position",
+    "underline-thickness",
+    "unicode",
+    "unicode-bidi",
+    "unicode-range",
+    "union",
+    "units-per-em",
+    "uplimit",
+    "use",
+    "usemap",
+    "v-alphabetic",
+    "v-hanging",
+    "v-ideographic",
+    "v-mathematical",
+    "valign",
+    "value",
+    "values",
+    "valuetype",
+    "var",
+    "variance",
+    "vector",
+    "vector-effect",
+    "vectorproduct",
+    "version",
+    "vert-adv-y",
+    "vert-origin-x",
+    "vert-origin-y",
+    "video",
+    "view",
+    "viewBox",
+    "viewTarget",
+    "viewbox",
+    "viewtarget",
+    "visibility",
+    "vkern",
+    "vlink",
+    "voffset",
+    "vspace",
+    "wbr",
+    "width",
+    "widths",
+    "word-spacing",
+    "wrap",
+    "writing-mode",
+    "writingsuggestions",
+    "x",
+    "x-height",
+    "x1",
+    "x2",
+    "xChannelSelector",
+    "xchannelselector",
+    "xlink",
+    "xlink:actuate",
+    "xlink:arcrole",
+    "xlink:href",
+    "xlink:role",
+    "xlink:show",
+    "xlink:title",
+    "xlink:type",
+    "xml:lang",
+    "xml:space",
+    "xmlns",
+    "xmlns:xlink",
+    "xmp",
+    "xor",
+    "xref",
+    "y",
+    "y1",
+    "y2",
+    "yChannelSelector",
+    "ychannelselector",
+    "z",
+    "zoomAndPan",
+    "zoomandpan",
+];
+
+impl LocalName {
+    pub const A: LocalName = LocalName::from_index(0);
+    pub const ABBR: LocalName = LocalName::from_index(1);
+    pub const ABS: LocalName = LocalName::from_index(2);
+    pub const ACCENT: LocalName = LocalName::from_index(3);
+    pub const ACCENT_HEIGHT: LocalName = LocalName::from_index(4);
+    pub const ACCENTUNDER: LocalName = LocalName::from_index(5);
+    pub const ACCEPT: LocalName = LocalName::from_index(6);
+    pub const ACCEPT_CHARSET: LocalName = LocalName::from_index(7);
+    pub const ACCESSKEY: LocalName = LocalName::from_index(8);
+    pub const ACCUMULATE: LocalName = LocalName::from_index(9);
+    pub const ACRONYM: LocalName = LocalName::from_index(10);
+    pub const ACTION: LocalName = LocalName::from_index(11);
+    pub const ACTIONTYPE: LocalName = LocalName::from_index(12);
+    pub const ACTUATE: LocalName = LocalName::from_index(13);
+    pub const ADDITIVE: LocalName = LocalName::from_index(14);
+    pub const ADDRESS: LocalName = LocalName::from_index(15);
+    pub const ALIGN: LocalName = LocalName::from_index(16);
+    pub const ALIGNMENT_BASELINE: LocalName = LocalName::from_index(17);
+    pub const ALIGNMENTSCOPE: LocalName = LocalName::from_index(18);
+    pub const ALINK: LocalName = LocalName::from_index(19);
+    pub const ALLOW: LocalName = LocalName::from_index(20);
+    pub const ALLOWFULLSCREEN: LocalName = LocalName::from_index(21);
+    pub const ALPHABETIC: LocalName = LocalName::from_index(22);
+    pub const ALT: LocalName = LocalName::from_index(23);
+    pub const ALT_GLYPH: LocalName = LocalName::from_index(24);
+    pub const ALT_GLYPH_DEF: LocalName = LocalName::from_index(25);
+    pub const ALT_GLYPH_ITEM: LocalName = LocalName::from_index(26);
+    pub const ALTGLYPH: LocalName = LocalName::from_index(27);
+    pub const ALTGLYPHDEF: LocalName = LocalName::from_index(28);
+    pub const ALTGLYPHITEM: LocalName = LocalName::from_index(29);
+    pub const ALTIMG: LocalName = LocalName::from_index(30);
+    pub const ALTIMG_HEIGHT: LocalName = LocalName::from_index(31);
+    pub const ALTIMG_VALIGN: LocalName = LocalName::from_index(32);
+    pub const ALTIMG_WIDTH: LocalName = LocalName::from_index(33);
+    pub const ALTTEXT: LocalName = LocalName::from_index(34);
+    pub const AMPLITUDE: LocalName = LocalName::from_index(35);
+    pub const AND: LocalName = LocalName::from_index(36);
+    pub const ANIMATE: LocalName = LocalName::from_index(37);
+    pub const ANIMATE_COLOR: LocalName = LocalName::from_index(38);
+    pub const ANIMATE_MOTION: LocalName = LocalName::from_index(39);
+    pub const ANIMATE_TRANSFORM: LocalName = LocalName::from_index(40);
+    pub const ANIMATECOLOR: LocalName = LocalName::from_index(41);
+    pub const ANIMATEMOTION: LocalName = LocalName::from_index(42);
+    pub const ANIMATETRANSFORM: LocalName = LocalName::from_index(43);
+    pub const ANNOTATION: LocalName = LocalName::from_index(44);
+    pub const ANNOTATION_XML: LocalName = LocalName::from_index(45);
+    pub const APPLET: LocalName = LocalName::from_index(46);
+    pub const APPLY: LocalName = LocalName::from_index(47);
+    pub const APPROX: LocalName = LocalName::from_index(48);
+    pub const ARABIC_FORM: LocalName = LocalName::from_index(49);
+    pub const ARCCOS: LocalName = LocalName::from_index(50);
+    pub const ARCCOSH: LocalName = LocalName::from_index(51);
+    pub const ARCCOT: LocalName = LocalName::from_index(52);
+    pub const ARCCOTH: LocalName = LocalName::from_index(53);
+    pub const ARCCSC: LocalName = LocalName::from_index(54);
+    pub const ARCCSCH: LocalName = LocalName::from_index(55);
+    pub const ARCHIVE: LocalName = LocalName::from_index(56);
+    pub const ARCROLE: LocalName = LocalName::from_index(57);
+    pub const ARCSEC: LocalName = LocalName::from_index(58);
+    pub const ARCSECH: LocalName = LocalName::from_index(59);
+    pub const ARCSIN: LocalName = LocalName::from_index(60);
+    pub const ARCSINH: LocalName = LocalName::from_index(61);
+    pub const ARCTAN: LocalName = LocalName::from_index(62);
+    pub const ARCTANH: LocalName = LocalName::from_index(63);
+    pub const AREA: LocalName = LocalName::from_index(64);
+    pub const ARG: LocalName = LocalName::from_index(65);
+    pub const ARIA_ACTIVEDESCENDANT: LocalName = LocalName::from_index(66);
+    pub const ARIA_ATOMIC: LocalName = LocalName::from_index(67);
+    pub const ARIA_AUTOCOMPLETE: LocalName = LocalName::from_index(68);
+    pub const ARIA_BRAILLELABEL: LocalName = LocalName::from_index(69);
+    pub const ARIA_BRAILLEROLEDESCRIPTION: LocalName = LocalName::from_index(70);
+    pub const ARIA_BUSY: LocalName = LocalName::from_index(71);
+    pub const ARIA_CHECKED: LocalName = LocalName::from_index(72);
+    pub const ARIA_COLCOUNT: LocalName = LocalName::from_index(73);
+    pub const ARIA_COLINDEX: LocalName = LocalName::from_index(74);
+    pub const ARIA_COLINDEXTEXT: LocalName = LocalName::from_index(75);
+    pub const ARIA_COLSPAN: LocalName = LocalName::from_index(76);
+    pub const ARIA_CONTROLS: LocalName = LocalName::from_index(77);
+    pub const ARIA_CURRENT: LocalName = LocalName::from_index(78);
+    pub const ARIA_DESCRIBEDBY: LocalName = LocalName::from_index(79);
+    pub const ARIA_DESCRIPTION: LocalName = LocalName::from_index(80);
+    pub const ARIA_DETAILS: LocalName = LocalName::from_index(81);
+    pub const ARIA_DISABLED: LocalName = LocalName::from_index(82);
+    pub const ARIA_ERRORMESSAGE: LocalName = LocalName::from_index(83);
+    pub const ARIA_EXPANDED: LocalName = LocalName::from_index(84);
+    pub const ARIA_FLOWTO: LocalName = LocalName::from_index(85);
+    pub const ARIA_HASPOPUP: LocalName = LocalName::from_index(86);
+    pub const ARIA_HIDDEN: LocalName = LocalName::from_index(87);
+    pub const ARIA_INVALID: LocalName = LocalName::from_index(88);
+    pub const ARIA_KEYSHORTCUTS: LocalName = LocalName::from_index(89);
+    pub const ARIA_LABEL: LocalName = LocalName::from_index(90);
+    pub const ARIA_LABELLEDBY: LocalName = LocalName::from_index(91);
+    pub const ARIA_LEVEL: LocalName = LocalName::from_index(92);
+    pub const ARIA_LIVE: LocalName = LocalName::from_index(93);
+    pub const ARIA_MODAL: LocalName = LocalName::from_index(94);
+    pub const ARIA_MULTILINE: LocalName = LocalName::from_index(95);
+    pub const ARIA_MULTISELECTABLE: LocalName = LocalName::from_index(96);
+    pub const ARIA_ORIENTATION: LocalName = LocalName::from_index(97);
+    pub const ARIA_OWNS: LocalName = LocalName::from_index(98);
+    pub const ARIA_PLACEHOLDER: LocalName = LocalName::from_index(99);
+    pub const ARIA_POSINSET: LocalName = LocalName::from_index(100);
+    pub const ARIA_PRESSED: LocalName = LocalName::from_index(101);
+    pub const ARIA_READONLY: LocalName = LocalName::from_index(102);
+    pub const ARIA_RELEVANT: LocalName = LocalName::from_index(103);
+    pub const ARIA_REQUIRED: LocalName = LocalName::from_index(104);
+    pub const ARIA_ROLEDESCRIPTION: LocalName = LocalName::from_index(105);
+    pub const ARIA_ROWCOUNT: LocalName = LocalName::from_index(106);
+    pub const ARIA_ROWINDEX: LocalName = LocalName::from_index(107);
+    pub const ARIA_ROWINDEXTEXT: LocalName = LocalName::from_index(108);
+    pub const ARIA_ROWSPAN: LocalName = LocalName::from_index(109);
+    pub const ARIA_SELECTED: LocalName = LocalName::from_index(110);
+    pub const ARIA_SETSIZE: LocalName = LocalName::from_index(111);
+    pub const ARIA_SORT: LocalName = LocalName::from_index(112);
+    pub const ARIA_VALUEMAX: LocalName = LocalName::from_index(113);
+    pub const ARIA_VALUEMIN: LocalName = LocalName::from_index(114);
+    pub const ARIA_VALUENOW: LocalName = LocalName::from_index(115);
+    pub const ARIA_VALUETEXT: LocalName = LocalName::from_index(116);
+    pub const ARTICLE: LocalName = LocalName::from_index(117);
+    pub const ASCENT: LocalName = LocalName::from_index(118);
+    pub const ASIDE: LocalName = LocalName::from_index(119);
+    pub const ASYNC: LocalName = LocalName::from_index(120);
+    pub const ATTRIBUTE_NAME: LocalName = LocalName::from_index(121);
+    pub const ATTRIBUTE_TYPE: LocalName = LocalName::from_index(122);
+    pub const ATTRIBUTENAME: LocalName = LocalName::from_index(123);
+    pub const ATTRIBUTETYPE: LocalName = LocalName::from_index(124);
+    pub const AUDIO: LocalName = LocalName::from_index(125);
+    pub const AUTOCAPITALIZE: LocalName = LocalName::from_index(126);
+    pub const AUTOCOMPLETE: LocalName = LocalName::from_index(127);
+    pub const AUTOCORRECT: LocalName = LocalName::from_index(128);
+    pub const AUTOFOCUS: LocalName = LocalName::from_index(129);
+    pub const AUTOPLAY: LocalName = LocalName::from_index(130);
+    pub const AXIS: LocalName = LocalName::from_index(131);
+    pub const AZIMUTH: LocalName = LocalName::from_index(132);
+    pub const B: LocalName = LocalName::from_index(133);
+    pub const BACKGROUND: LocalName = LocalName::from_index(134);
+    pub const BASE: LocalName = LocalName::from_index(135);
+    pub const BASE_FREQUENCY: LocalName = LocalName::from_index(136);
+    pub const BASE_PROFILE: LocalName = LocalName::from_index(137);
+    pub const BASEFONT: LocalName = LocalName::from_index(138);
+    pub const BASEFREQUENCY: LocalName = LocalName::from_index(139);
+    pub const BASELINE_SHIFT: LocalName = LocalName::from_index(140);
+    pub const BASEPROFILE: LocalName = LocalName::from_index(141);
+    pub const BBOX: LocalName = LocalName::from_index(142);
+    pub const BDI: LocalName = LocalName::from_index(143);
+    pub const BDO: LocalName = LocalName::from_index(144);
+    pub const BEGIN: LocalName = LocalName::from_index(145);
+    pub const BEVELLED: LocalName = LocalName::from_index(146);
+    pub const BGCOLOR: LocalName = LocalName::from_index(147);
+    pub const BGSOUND: LocalName = LocalName::from_index(148);
+    pub const BIAS: LocalName = LocalName::from_index(149);
+    pub const BIG: LocalName = LocalName::from_index(150);
+    pub const BIND: LocalName = LocalName::from_index(151);
+    pub const BLINK: LocalName = LocalName::from_index(152);
+    pub const BLOCKING: LocalName = LocalName::from_index(153);
+    pub const BLOCKQUOTE: LocalName = LocalName::from_index(154);
+    pub const BODY: LocalName = LocalName::from_index(155);
+    pub const BORDER: LocalName = LocalName::from_index(156);
+    pub const BR: LocalName = LocalName::from_index(157);
+    pub const BUTTON: LocalName = LocalName::from_index(158);
+    pub const BVAR: LocalName = LocalName::from_index(159);
+    pub const BY: LocalName = LocalName::from_index(160);
+    pub const CALC_MODE: LocalName = LocalName::from_index(161);
+    pub const CALCMODE: LocalName = LocalName::from_index(162);
+    pub const CANVAS: LocalName = LocalName::from_index(163);
+    pub const CAP_HEIGHT: LocalName = LocalName::from_index(164);
+    pub const CAPTION: LocalName = LocalName::from_index(165);
+    pub const CARD: LocalName = LocalName::from_index(166);
+    pub const CARTESIANPRODUCT: LocalName = LocalName::from_index(167);
+    pub const CBYTES: LocalName = LocalName::from_index(168);
+    pub const CD: LocalName = LocalName::from_index(169);
+    pub const CDGROUP: LocalName = LocalName::from_index(170);
+    pub const CEILING: LocalName = LocalName::from_index(171);
+    pub const CELLPADDING: LocalName = LocalName::from_index(172);
+    pub const CELLSPACING: LocalName = LocalName::from_index(173);
+    pub const CENTER: LocalName = LocalName::from_index(174);
+    pub const CERROR: LocalName = LocalName::from_index(175);
+    pub const CHAR: LocalName = LocalName::from_index(176);
+    pub const CHARALIGN: LocalName = LocalName::from_index(177);
+    pub const CHAROFF: LocalName = LocalName::from_index(178);
+    pub const CHARSET: LocalName = LocalName::from_index(179);
+    pub const CHECKED: LocalName = LocalName::from_index(180);
+    pub const CI: LocalName = LocalName::from_index(181);
+    pub const CIRCLE: LocalName = LocalName::from_index(182);
+    pub const CITE: LocalName = LocalName::from_index(183);
+    pub const CLASS: LocalName = LocalName::from_index(184);
+    pub const CLASSID: LocalName = LocalName::from_index(185);
+    pub const CLEAR: LocalName = LocalName::from_index(186);
+    pub const CLIP: LocalName = LocalName::from_index(187);
+    pub const CLIP_PATH: LocalName = LocalName::from_index(188);
+    pub const CLIP_RULE: LocalName = LocalName::from_index(189);
+    pub const CLIP_PATH_ELEMENT: LocalName = LocalName::from_index(190);
+    pub const CLIP_PATH_UNITS: LocalName = LocalName::from_index(191);
+    pub const CLIPPATH: LocalName = LocalName::from_index(192);
+    pub const CLIPPATHUNITS: LocalName = LocalName::from_index(193);
+    pub const CLOSE: LocalName = LocalName::from_index(194);
+    pub const CLOSURE: LocalName = LocalName::from_index(195);
+    pub const CN: LocalName = LocalName::from_index(196);
+    pub const CODE: LocalName = LocalName::from_index(197);
+    pub const CODEBASE: LocalName = LocalName::from_index(198);
+    pub const CODETYPE: LocalName = LocalName::from_index(199);
+    pub const CODOMAIN: LocalName = LocalName::from_index(200);
+    pub const COL: LocalName = LocalName::from_index(201);
+    pub const COLGROUP: LocalName = LocalName::from_index(202);
+    pub const COLOR: LocalName = LocalName::from_index(203);
+    pub const COLOR_INTERPOLATION: LocalName = LocalName::from_index(204);
+    pub const COLOR_INTERPOLATION_FILTERS: LocalName = LocalName::from_index(205);
+    pub const COLOR_PROFILE: LocalName = LocalName::from_index(206);
+    pub const COLOR_RENDERING: LocalName = LocalName::from_index(207);
+    pub const COLS: LocalName = LocalName::from_index(208);
+    pub const COLSPAN: LocalName = LocalName::from_index(209);
+    pub const COLUMNALIGN: LocalName = LocalName::from_index(210);
+    pub const COLUMNLINES: LocalName = LocalName::from_index(211);
+    pub const COLUMNSPACING: LocalName = LocalName::from_index(212);
+    pub const COLUMNSPAN: LocalName = LocalName::from_index(213);
+    pub const COLUMNWIDTH: LocalName = LocalName::from_index(214);
+    pub const COMPACT: LocalName = LocalName::from_index(215);
+    pub const COMPLEXES: LocalName = LocalName::from_index(216);
+    pub const COMPOSE: LocalName = LocalName::from_index(217);
+    pub const CONDITION: LocalName = LocalName::from_index(218);
+    pub const CONJUGATE: LocalName = LocalName::from_index(219);
+    pub const CONTENT: LocalName = LocalName::from_index(220);
+    pub const CONTENT_SCRIPT_TYPE: LocalName = LocalName::from_index(221);
+    pub const CONTENT_STYLE_TYPE: LocalName = LocalName::from_index(222);
+    pub const CONTENTEDITABLE: LocalName = LocalName::from_index(223);
+    pub const CONTENTSCRIPTTYPE: LocalName = LocalName::from_index(224);
+    pub const CONTENTSTYLETYPE: LocalName = LocalName::from_index(225);
+    pub const CONTROLS: LocalName = LocalName::from_index(226);
+    pub const COORDS: LocalName = LocalName::from_index(227);
+    pub const COS: LocalName = LocalName::from_index(228);
+    pub const COSH: LocalName = LocalName::from_index(229);
+    pub const COT: LocalName = LocalName::from_index(230);
+    pub const COTH: LocalName = LocalName::from_index(231);
+    pub const CROSSORIGIN: LocalName = LocalName::from_index(232);
+    pub const CROSSOUT: LocalName = LocalName::from_index(233);
+    pub const CS: LocalName = LocalName::from_index(234);
+    pub const CSC: LocalName = LocalName::from_index(235);
+    pub const CSCH: LocalName = LocalName::from_index(236);
+    pub const CSYMBOL: LocalName = LocalName::from_index(237);
+    pub const CURL: LocalName = LocalName::from_index(238);
+    pub const CURSOR: LocalName = LocalName::from_index(239);
+    pub const CX: LocalName = LocalName::from_index(240);
+    pub const CY: LocalName = LocalName::from_index(241);
+    pub const D: LocalName = LocalName::from_index(242);
+    pub const DATA: LocalName = LocalName::from_index(243);
+    pub const DATALIST: LocalName = LocalName::from_index(244);
+    pub const DATETIME: LocalName = LocalName::from_index(245);
+    pub const DD: LocalName = LocalName::from_index(246);
+    pub const DECELERATE: LocalName = LocalName::from_index(247);
+    pub const DECIMALPOINT: LocalName = LocalName::from_index(248);
+    pub const DECLARE: LocalName = LocalName::from_index(249);
+    pub const DECODING: LocalName = LocalName::from_index(250);
+    pub const DEFAULT: LocalName = LocalName::from_index(251);
+    pub const DEFER: LocalName = LocalName::from_index(252);
+    pub const DEFINITION_URL: LocalName = LocalName::from_index(253);
+    pub const DEFINITIONURL: LocalName = LocalName::from_index(254);
+    pub const DEFS: LocalName = LocalName::from_index(255);
+    pub const DEGREE: LocalName = LocalName::from_index(256);
+    pub const DEL: LocalName = LocalName::from_index(257);
+    pub const DENOMALIGN: LocalName = LocalName::from_index(258);
+    pub const DEPTH: LocalName = LocalName::from_index(259);
+    pub const DESC: LocalName = LocalName::from_index(260);
+    pub const DESCENT: LocalName = LocalName::from_index(261);
+    pub const DETAILS: LocalName = LocalName::from_index(262);
+    pub const DETERMINANT: LocalName = LocalName::from_index(263);
+    pub const DFN: LocalName = LocalName::from_index(264);
+    pub const DIALOG: LocalName = LocalName::from_index(265);
+    pub const DIFF: LocalName = LocalName::from_index(266);
+    pub const DIFFUSE_CONSTANT: LocalName = LocalName::from_index(267);
+    pub const DIFFUSECONSTANT: LocalName = LocalName::from_index(268);
+    pub const DIR: LocalName = LocalName::from_index(269);
+    pub const DIRECTION: LocalName = LocalName::from_index(270);
+    pub const DIRNAME: LocalName = LocalName::from_index(271);
+    pub const DISABLED: LocalName = LocalName::from_index(272);
+    pub const DISCARD: LocalName = LocalName::from_index(273);
+    pub const DISPLAY: LocalName = LocalName::from_index(274);
+    pub const DISPLAYSTYLE: LocalName = LocalName::from_index(275);
+    pub const DIV: LocalName = LocalName::from_index(276);
+    pub const DIVERGENCE: LocalName = LocalName::from_index(277);
+    pub const DIVIDE: LocalName = LocalName::from_index(278);
+    pub const DIVISOR: LocalName = LocalName::from_index(279);
+    pub const DL: LocalName = LocalName::from_index(280);
+    pub const DOMAIN: LocalName = LocalName::from_index(281);
+    pub const DOMAINOFAPPLICATION: LocalName = LocalName::from_index(282);
+    pub const DOMINANT_BASELINE: LocalName = LocalName::from_index(283);
+    pub const DOWNLOAD: LocalName = LocalName::from_index(284);
+    pub const DRAGGABLE: LocalName = LocalName::from_index(285);
+    pub const DT: LocalName = LocalName::from_index(286);
+    pub const DUR: LocalName = LocalName::from_index(287);
+    pub const DX: LocalName = LocalName::from_index(288);
+    pub const DY: LocalName = LocalName::from_index(289);
+    pub const EDGE: LocalName = LocalName::from_index(290);
+    pub const EDGE_MODE: LocalName = LocalName::from_index(291);
+    pub const EDGEMODE: LocalName = LocalName::from_index(292);
+    pub const ELEVATION: LocalName = LocalName::from_index(293);
+    pub const ELLIPSE: LocalName = LocalName::from_index(294);
+    pub const EM: LocalName = LocalName::from_index(295);
+    pub const EMBED: LocalName = LocalName::from_index(296);
+    pub const EMPTYSET: LocalName = LocalName::from_index(297);
+    pub const ENABLE_BACKGROUND: LocalName = LocalName::from_index(298);
+    pub const ENCODING: LocalName = LocalName::from_index(299);
+    pub const ENCTYPE: LocalName = LocalName::from_index(300);
+    pub const END: LocalName = LocalName::from_index(301);
+    pub const ENTERKEYHINT: LocalName = LocalName::from_index(302);
+    pub const EQ: LocalName = LocalName::from_index(303);
+    pub const EQUALCOLUMNS: LocalName = LocalName::from_index(304);
+    pub const EQUALROWS: LocalName = LocalName::from_index(305);
+    pub const EQUIVALENT: LocalName = LocalName::from_index(306);
+    pub const EULERGAMMA: LocalName = LocalName::from_index(307);
+    pub const EXISTS: LocalName = LocalName::from_index(308);
+    pub const EXP: LocalName = LocalName::from_index(309);
+    pub const EXPONENT: LocalName = LocalName::from_index(310);
+    pub const EXPONENTIALE: LocalName = LocalName::from_index(311);
+    pub const EXTERNAL_RESOURCES_REQUIRED: LocalName = LocalName::from_index(312);
+    pub const EXTERNALRESOURCESREQUIRED: LocalName = LocalName::from_index(313);
+    pub const FACE: LocalName = LocalName::from_index(314);
+    pub const FACTORIAL: LocalName = LocalName::from_index(315);
+    pub const FACTOROF: LocalName = LocalName::from_index(316);
+    pub const FALSE: LocalName = LocalName::from_index(317);
+    pub const FE_BLEND: LocalName = LocalName::from_index(318);
+    pub const FE_COLOR_MATRIX: LocalName = LocalName::from_index(319);
+    pub const FE_COMPONENT_TRANSFER: LocalName = LocalName::from_index(320);
+    pub const FE_COMPOSITE: LocalName = LocalName::from_index(321);
+    pub const FE_CONVOLVE_MATRIX: LocalName = LocalName::from_index(322);
+    pub const FE_DIFFUSE_LIGHTING: LocalName = LocalName::from_index(323);
+    pub const FE_DISPLACEMENT_MAP: LocalName = LocalName::from_index(324);
+    pub const FE_DISTANT_LIGHT: LocalName = LocalName::from_index(325);
+    pub const FE_DROP_SHADOW: LocalName = LocalName::from_index(326);
+    pub const FE_FLOOD: LocalName = LocalName::from_index(327);
+    pub const FE_FUNC_A: LocalName = LocalName::from_index(328);
+    pub const FE_FUNC_B: LocalName = LocalName::from_index(329);
+    pub const FE_FUNC_G: LocalName = LocalName::from_index(330);
+    pub const FE_FUNC_R: LocalName = LocalName::from_index(331);
+    pub const FE_GAUSSIAN_BLUR: LocalName = LocalName::from_index(332);
+    pub const FE_IMAGE: LocalName = LocalName::from_index(333);
+    pub const FE_MERGE: LocalName = LocalName::from_index(334);
+    pub const FE_MERGE_NODE: LocalName = LocalName::from_index(335);
+    pub const FE_MORPHOLOGY: LocalName = LocalName::from_index(336);
+    pub const FE_OFFSET: LocalName = LocalName::from_index(337);
+    pub const FE_POINT_LIGHT: LocalName = LocalName::from_index(338);
+    pub const FE_SPECULAR_LIGHTING: LocalName = LocalName::from_index(339);
+    pub const FE_SPOT_LIGHT: LocalName = LocalName::from_index(340);
+    pub const FE_TILE: LocalName = LocalName::from_index(341);
+    pub const FE_TURBULENCE: LocalName = LocalName::from_index(342);
+    pub const FEBLEND: LocalName = LocalName::from_index(343);
+    pub const FECOLORMATRIX: LocalName = LocalName::from_index(344);
+    pub const FECOMPONENTTRANSFER: LocalName = LocalName::from_index(345);
+    pub const FECOMPOSITE: LocalName = LocalName::from_index(346);
+    pub const FECONVOLVEMATRIX: LocalName = LocalName::from_index(347);
+    pub const FEDIFFUSELIGHTING: LocalName = LocalName::from_index(348);
+    pub const FEDISPLACEMENTMAP: LocalName = LocalName::from_index(349);
+    pub const FEDISTANTLIGHT: LocalName = LocalName::from_index(350);
+    pub const FEDROPSHADOW: LocalName = LocalName::from_index(351);
+    pub const FEFLOOD: LocalName = LocalName::from_index(352);
+    pub const FEFUNCA: LocalName = LocalName::from_index(353);
+    pub const FEFUNCB: LocalName = LocalName::from_index(354);
+    pub const FEFUNCG: LocalName = LocalName::from_index(355);
+    pub const FEFUNCR: LocalName = LocalName::from_index(356);
+    pub const FEGAUSSIANBLUR: LocalName = LocalName::from_index(357);
+    pub const FEIMAGE: LocalName = LocalName::from_index(358);
+    pub const FEMERGE: LocalName = LocalName::from_index(359);
+    pub const FEMERGENODE: LocalName = LocalName::from_index(360);
+    pub const FEMORPHOLOGY: LocalName = LocalName::from_index(361);
+    pub const FENCE: LocalName = LocalName::from_index(362);
+    pub const FEOFFSET: LocalName = LocalName::from_index(363);
+    pub const FEPOINTLIGHT: LocalName = LocalName::from_index(364);
+    pub const FESPECULARLIGHTING: LocalName = LocalName::from_index(365);
+    pub const FESPOTLIGHT: LocalName = LocalName::from_index(366);
+    pub const FETCHPRIORITY: LocalName = LocalName::from_index(367);
+    pub const FETILE: LocalName = LocalName::from_index(368);
+    pub const FETURBULENCE: LocalName = LocalName::from_index(369);
+    pub const FIELDSET: LocalName = LocalName::from_index(370);
+    pub const FIGCAPTION: LocalName = LocalName::from_index(371);
+    pub const FIGURE: LocalName = LocalName::from_index(372);
+    pub const FILL: LocalName = LocalName::from_index(373);
+    pub const FILL_OPACITY: LocalName = LocalName::from_index(374);
+    pub const FILL_RULE: LocalName = LocalName::from_index(375);
+    pub const FILTER: LocalName = LocalName::from_index(376);
+    pub const FILTER_RES: LocalName = LocalName::from_index(377);
+    pub const FILTER_UNITS: LocalName = LocalName::from_index(378);
+    pub const FILTERRES: LocalName = LocalName::from_index(379);
+    pub const FILTERUNITS: LocalName = LocalName::from_index(380);
+    pub const FLOOD_COLOR: LocalName = LocalName::from_index(381);
+    pub const FLOOD_OPACITY: LocalName = LocalName::from_index(382);
+    pub const FLOOR: LocalName = LocalName::from_index(383);
+    pub const FN: LocalName = LocalName::from_index(384);
+    pub const FONT: LocalName = LocalName::from_index(385);
+    pub const FONT_FACE: LocalName = LocalName::from_index(386);
+    pub const FONT_FACE_FORMAT: LocalName = LocalName::from_index(387);
+    pub const FONT_FACE_NAME: LocalName = LocalName::from_index(388);
+    pub const FONT_FACE_SRC: LocalName = LocalName::from_index(389);
+    pub const FONT_FACE_URI: LocalName = LocalName::from_index(390);
+    pub const FONT_FAMILY: LocalName = LocalName::from_index(391);
+    pub const FONT_SIZE: LocalName = LocalName::from_index(392);
+    pub const FONT_SIZE_ADJUST: LocalName = LocalName::from_index(393);
+    pub const FONT_STRETCH: LocalName = LocalName::from_index(394);
+    pub const FONT_STYLE: LocalName = LocalName::from_index(395);
+    pub const FONT_VARIANT: LocalName = LocalName::from_index(396);
+    pub const FONT_WEIGHT: LocalName = LocalName::from_index(397);
+    pub const FONTFAMILY: LocalName = LocalName::from_index(398);
+    pub const FONTSIZE: LocalName = LocalName::from_index(399);
+    pub const FONTSTYLE: LocalName = LocalName::from_index(400);
+    pub const FONTWEIGHT: LocalName = LocalName::from_index(401);
+    pub const FOOTER: LocalName = LocalName::from_index(402);
+    pub const FOR: LocalName = LocalName::from_index(403);
+    pub const FORALL: LocalName = LocalName::from_index(404);
+    pub const FOREIGN_OBJECT: LocalName = LocalName::from_index(405);
+    pub const FOREIGNOBJECT: LocalName = LocalName::from_index(406);
+    pub const FORM: LocalName = LocalName::from_index(407);
+    pub const FORMACTION: LocalName = LocalName::from_index(408);
+    pub const FORMAT: LocalName = LocalName::from_index(409);
+    pub const FORMENCTYPE: LocalName = LocalName::from_index(410);
+    pub const FORMMETHOD: LocalName = LocalName::from_index(411);
+    pub const FORMNOVALIDATE: LocalName = LocalName::from_index(412);
+    pub const FORMTARGET: LocalName = LocalName::from_index(413);
+    pub const FR: LocalName = LocalName::from_index(414);
+    pub const FRAME: LocalName = LocalName::from_index(415);
+    pub const FRAMEBORDER: LocalName = LocalName::from_index(416);
+    pub const FRAMESET: LocalName = LocalName::from_index(417);
+    pub const FRAMESPACING: LocalName = LocalName::from_index(418);
+    pub const FROM: LocalName = LocalName::from_index(419);
+    pub const FX: LocalName = LocalName::from_index(420);
+    pub const FY: LocalName = LocalName::from_index(421);
+    pub const G: LocalName = LocalName::from_index(422);
+    pub const G1: LocalName = LocalName::from_index(423);
+    pub const G2: LocalName = LocalName::from_index(424);
+    pub const GCD: LocalName = LocalName::from_index(425);
+    pub const GEQ: LocalName = LocalName::from_index(426);
+    pub const GLYPH: LocalName = LocalName::from_index(427);
+    pub const GLYPH_NAME: LocalName = LocalName::from_index(428);
+    pub const GLYPH_ORIENTATION_HORIZONTAL: LocalName = LocalName::from_index(429);
+    pub const GLYPH_ORIENTATION_VERTICAL: LocalName = LocalName::from_index(430);
+    pub const GLYPH_REF: LocalName = LocalName::from_index(431);
+    pub const GLYPHREF: LocalName = LocalName::from_index(432);
+    pub const GRAD: LocalName = LocalName::from_index(433);
+    pub const GRADIENT_TRANSFORM: LocalName = LocalName::from_index(434);
+    pub const GRADIENT_UNITS: LocalName = LocalName::from_index(435);
+    pub const GRADIENTTRANSFORM: LocalName = LocalName::from_index(436);
+    pub const GRADIENTUNITS: LocalName = LocalName::from_index(437);
+    pub const GROUPALIGN: LocalName = LocalName::from_index(438);
+    pub const GT: LocalName = LocalName::from_index(439);
+    pub const H1: LocalName = LocalName::from_index(440);
+    pub const H2: LocalName = LocalName::from_index(441);
+    pub const H3: LocalName = LocalName::from_index(442);
+    pub const H4: LocalName = LocalName::from_index(443);
+    pub const H5: LocalName = LocalName::from_index(444);
+    pub const H6: LocalName = LocalName::from_index(445);
+    pub const HANGING: LocalName = LocalName::from_index(446);
+    pub const HEAD: LocalName = LocalName::from_index(447);
+    pub const HEADER: LocalName = LocalName::from_index(448);
+    pub const HEADERS: LocalName = LocalName::from_index(449);
+    pub const HEIGHT: LocalName = LocalName::from_index(450);
+    pub const HGROUP: LocalName = LocalName::from_index(451);
+    pub const HIDDEN: LocalName = LocalName::from_index(452);
+    pub const HIGH: LocalName = LocalName::from_index(453);
+    pub const HKERN: LocalName = LocalName::from_index(454);
+    pub const HORIZ_ADV_X: LocalName = LocalName::from_index(455);
+    pub const HORIZ_ORIGIN_X: LocalName = LocalName::from_index(456);
+    pub const HR: LocalName = LocalName::from_index(457);
+    pub const HREF: LocalName = LocalName::from_index(458);
+    pub const HREFLANG: LocalName = LocalName::from_index(459);
+    pub const HSPACE: LocalName = LocalName::from_index(460);
+    pub const HTML: LocalName = LocalName::from_index(461);
+    pub const HTTP_EQUIV: LocalName = LocalName::from_index(462);
+    pub const I: LocalName = LocalName::from_index(463);
+    pub const ID: LocalName = LocalName::from_index(464);
+    pub const IDENT: LocalName = LocalName::from_index(465);
+    pub const IDEOGRAPHIC: LocalName = LocalName::from_index(466);
+    pub const IFRAME: LocalName = LocalName::from_index(467);
+    pub const IMAGE: LocalName = LocalName::from_index(468);
+    pub const IMAGE_RENDERING: LocalName = LocalName::from_index(469);
+    pub const IMAGESIZES: LocalName = LocalName::from_index(470);
+    pub const IMAGESRCSET: LocalName = LocalName::from_index(471);
+    pub const IMAGINARY: LocalName = LocalName::from_index(472);
+    pub const IMAGINARYI: LocalName = LocalName::from_index(473);
+    pub const IMG: LocalName = LocalName::from_index(474);
+    pub const IMPLIES: LocalName = LocalName::from_index(475);
+    pub const IN: LocalName = LocalName::from_index(476);
+    pub const IN2: LocalName = LocalName::from_index(477);
+    pub const INDENTALIGN: LocalName = LocalName::from_index(478);
+    pub const INDENTALIGNFIRST: LocalName = LocalName::from_index(479);
+    pub const INDENTALIGNLAST: LocalName = LocalName::from_index(480);
+    pub const INDENTSHIFT: LocalName = LocalName::from_index(481);
+    pub const INDENTSHIFTFIRST: LocalName = LocalName::from_index(482);
+    pub const INDENTSHIFTLAST: LocalName = LocalName::from_index(483);
+    pub const INDENTTARGET: LocalName = LocalName::from_index(484);
+    pub const INERT: LocalName = LocalName::from_index(485);
+    pub const INFINITY: LocalName = LocalName::from_index(486);
+    pub const INFIXLINEBREAKSTYLE: LocalName = LocalName::from_index(487);
+    pub const INPUT: LocalName = LocalName::from_index(488);
+    pub const INPUTMODE: LocalName = LocalName::from_index(489);
+    pub const INS: LocalName = LocalName::from_index(490);
+    pub const INT: LocalName = LocalName::from_index(491);
+    pub const INTEGERS: LocalName = LocalName::from_index(492);
+    pub const INTEGRITY: LocalName = LocalName::from_index(493);
+    pub const INTERCEPT: LocalName = LocalName::from_index(494);
+    pub const INTERSECT: LocalName = LocalName::from_index(495);
+    pub const INTERVAL: LocalName = LocalName::from_index(496);
+    pub const INVERSE: LocalName = LocalName::from_index(497);
+    pub const IS: LocalName = LocalName::from_index(498);
+    pub const ISINDEX: LocalName = LocalName::from_index(499);
+    pub const ISMAP: LocalName = LocalName::from_index(500);
+    pub const ITEMID: LocalName = LocalName::from_index(501);
+    pub const ITEMPROP: LocalName = LocalName::from_index(502);
+    pub const ITEMREF: LocalName = LocalName::from_index(503);
+    pub const ITEMSCOPE: LocalName = LocalName::from_index(504);
+    pub const ITEMTYPE: LocalName = LocalName::from_index(505);
+    pub const K: LocalName = LocalName::from_index(506);
+    pub const K1: LocalName = LocalName::from_index(507);
+    pub const K2: LocalName = LocalName::from_index(508);
+    pub const K3: LocalName = LocalName::from_index(509);
+    pub const K4: LocalName = LocalName::from_index(510);
+    pub const KBD: LocalName = LocalName::from_index(511);
+    pub const KERNEL_MATRIX: LocalName = LocalName::from_index(512);
+    pub const KERNEL_UNIT_LENGTH: LocalName = LocalName::from_index(513);
+    pub const KERNELMATRIX: LocalName = LocalName::from_index(514);
+    pub const KERNELUNITLENGTH: LocalName = LocalName::from_index(515);
+    pub const KERNING: LocalName = LocalName::from_index(516);
+    pub const KEY_POINTS: LocalName = LocalName::from_index(517);
+    pub const KEY_SPLINES: LocalName = LocalName::from_index(518);
+    pub const KEY_TIMES: LocalName = LocalName::from_index(519);
+    pub const KEYGEN: LocalName = LocalName::from_index(520);
+    pub const KEYPOINTS: LocalName = LocalName::from_index(521);
+    pub const KEYSPLINES: LocalName = LocalName::from_index(522);
+    pub const KEYTIMES: LocalName = LocalName::from_index(523);
+    pub const KIND: LocalName = LocalName::from_index(524);
+    pub const LABEL: LocalName = LocalName::from_index(525);
+    pub const LAMBDA: LocalName = LocalName::from_index(526);
+    pub const LANG: LocalName = LocalName::from_index(527);
+    pub const LANGUAGE: LocalName = LocalName::from_index(528);
+    pub const LAPLACIAN: LocalName = LocalName::from_index(529);
+    pub const LARGEOP: LocalName = LocalName::from_index(530);
+    pub const LCM: LocalName = LocalName::from_index(531);
+    pub const LEGEND: LocalName = LocalName::from_index(532);
+    pub const LENGTH: LocalName = LocalName::from_index(533);
+    pub const LENGTH_ADJUST: LocalName = LocalName::from_index(534);
+    pub const LENGTHADJUST: LocalName = LocalName::from_index(535);
+    pub const LEQ: LocalName = LocalName::from_index(536);
+    pub const LETTER_SPACING: LocalName = LocalName::from_index(537);
+    pub const LI: LocalName = LocalName::from_index(538);
+    pub const LIGHTING_COLOR: LocalName = LocalName::from_index(539);
+    pub const LIMIT: LocalName = LocalName::from_index(540);
+    pub const LIMITING_CONE_ANGLE: LocalName = LocalName::from_index(541);
+    pub const LIMITINGCONEANGLE: LocalName = LocalName::from_index(542);
+    pub const LINE: LocalName = LocalName::from_index(543);
+    pub const LINEAR_GRADIENT: LocalName = LocalName::from_index(544);
+    pub const LINEARGRADIENT: LocalName = LocalName::from_index(545);
+    pub const LINEBREAK: LocalName = LocalName::from_index(546);
+    pub const LINEBREAKMULTCHAR: LocalName = LocalName::from_index(547);
+    pub const LINEBREAKSTYLE: LocalName = LocalName::from_index(548);
+    pub const LINELEADING: LocalName = LocalName::from_index(549);
+    pub const LINETHICKNESS: LocalName = LocalName::from_index(550);
+    pub const LINK: LocalName = LocalName::from_index(551);
+    pub const LIST: LocalName = LocalName::from_index(552);
+    pub const LISTING: LocalName = LocalName::from_index(553);
+    pub const LN: LocalName = LocalName::from_index(554);
+    pub const LOADING: LocalName = LocalName::from_index(555);
+    pub const LOCAL: LocalName = LocalName::from_index(556);
+    pub const LOCATION: LocalName = LocalName::from_index(557);
+    pub const LOG: LocalName = LocalName::from_index(558);
+    pub const LOGBASE: LocalName = LocalName::from_index(559);
+    pub const LONGDESC: LocalName = LocalName::from_index(560);
+    pub const LONGDIVSTYLE: LocalName = LocalName::from_index(561);
+    pub const LOOP: LocalName = LocalName::from_index(562);
+    pub const LOW: LocalName = LocalName::from_index(563);
+    pub const LOWLIMIT: LocalName = LocalName::from_index(564);
+    pub const LQUOTE: LocalName = LocalName::from_index(565);
+    pub const LSPACE: LocalName = LocalName::from_index(566);
+    pub const LT: LocalName = LocalName::from_index(567);
+    pub const MACROS: LocalName = LocalName::from_index(568);
+    pub const MACTION: LocalName = LocalName::from_index(569);
+    pub const MAIN: LocalName = LocalName::from_index(570);
+    pub const MALIGNGROUP: LocalName = LocalName::from_index(571);
+    pub const MALIGNMARK: LocalName = LocalName::from_index(572);
+    pub const MANIFEST: LocalName = LocalName::from_index(573);
+    pub const MAP: LocalName = LocalName::from_index(574);
+    pub const MARGINHEIGHT: LocalName = LocalName::from_index(575);
+    pub const MARGINWIDTH: LocalName = LocalName::from_index(576);
+    pub const MARK: LocalName = LocalName::from_index(577);
+    pub const MARKER: LocalName = LocalName::from_index(578);
+    pub const MARKER_END: LocalName = LocalName::from_index(579);
+    pub const MARKER_MID: LocalName = LocalName::from_index(580);
+    pub const MARKER_START: LocalName = LocalName::from_index(581);
+    pub const MARKER_HEIGHT: LocalName = LocalName::from_index(582);
+    pub const MARKER_UNITS: LocalName = LocalName::from_index(583);
+    pub const MARKER_WIDTH: LocalName = LocalName::from_index(584);
+    pub const MARKERHEIGHT: LocalName = LocalName::from_index(585);
+    pub const MARKERUNITS: LocalName = LocalName::from_index(586);
+    pub const MARKERWIDTH: LocalName = LocalName::from_index(587);
+    pub const MARQUEE: LocalName = LocalName::from_index(588);
+    pub const MASK: LocalName = LocalName::from_index(589);
+    pub const MASK_CONTENT_UNITS: LocalName = LocalName::from_index(590);
+    pub const MASK_UNITS: LocalName = LocalName::from_index(591);
+    pub const MASKCONTENTUNITS: LocalName = LocalName::from_index(592);
+    pub const MASKUNITS: LocalName = LocalName::from_index(593);
+    pub const MATH: LocalName = LocalName::from_index(594);
+    pub const MATHBACKGROUND: LocalName = LocalName::from_index(595);
+    pub const MATHCOLOR: LocalName = LocalName::from_index(596);
+    pub const MATHEMATICAL: LocalName = LocalName::from_index(597);
+    pub const MATHSIZE: LocalName = LocalName::from_index(598);
+    pub const MATHVARIANT: LocalName = LocalName::from_index(599);
+    pub const MATRIX: LocalName = LocalName::from_index(600);
+    pub const MATRIXROW: LocalName = LocalName::from_index(601);
+    pub const MAX: LocalName = LocalName::from_index(602);
+    pub const MAXLENGTH: LocalName = LocalName::from_index(603);
+    pub const MAXSIZE: LocalName = LocalName::from_index(604);
+    pub const MAXWIDTH: LocalName = LocalName::from_index(605);
+    pub const MEAN: LocalName = LocalName::from_index(606);
+    pub const MEDIA: LocalName = LocalName::from_index(607);
+    pub const MEDIAN: LocalName = LocalName::from_index(608);
+    pub const MENCLOSE: LocalName = LocalName::from_index(609);
+    pub const MENU: LocalName = LocalName::from_index(610);
+    pub const MENUITEM: LocalName = LocalName::from_index(611);
+    pub const MERROR: LocalName = LocalName::from_index(612);
+    pub const META: LocalName = LocalName::from_index(613);
+    pub const METADATA: LocalName = LocalName::from_index(614);
+    pub const METER: LocalName = LocalName::from_index(615);
+    pub const METHOD: LocalName = LocalName::from_index(616);
+    pub const MFENCED: LocalName = LocalName::from_index(617);
+    pub const MFRAC: LocalName = LocalName::from_index(618);
+    pub const MGLYPH: LocalName = LocalName::from_index(619);
+    pub const MI: LocalName = LocalName::from_index(620);
+    pub const MIN: LocalName = LocalName::from_index(621);
+    pub const MINLABELSPACING: LocalName = LocalName::from_index(622);
+    pub const MINLENGTH: LocalName = LocalName::from_index(623);
+    pub const MINSIZE: LocalName = LocalName::from_index(624);
+    pub const MINUS: LocalName = LocalName::from_index(625);
+    pub const MISSING_GLYPH: LocalName = LocalName::from_index(626);
+    pub const MLABELEDTR: LocalName = LocalName::from_index(627);
+    pub const MLONGDIV: LocalName = LocalName::from_index(628);
+    pub const MMULTISCRIPTS: LocalName = LocalName::from_index(629);
+    pub const MN: LocalName = LocalName::from_index(630);
+    pub const MO: LocalName = LocalName::from_index(631);
+    pub const MODE: LocalName = LocalName::from_index(632);
+    pub const MOMENT: LocalName = LocalName::from_index(633);
+    pub const MOMENTABOUT: LocalName = LocalName::from_index(634);
+    pub const MOVABLELIMITS: LocalName = LocalName::from_index(635);
+    pub const MOVER: LocalName = LocalName::from_index(636);
+    pub const MPADDED: LocalName = LocalName::from_index(637);
+    pub const MPATH: LocalName = LocalName::from_index(638);
+    pub const MPHANTOM: LocalName = LocalName::from_index(639);
+    pub const MPRESCRIPTS: LocalName = LocalName::from_index(640);
+    pub const MROOT: LocalName = LocalName::from_index(641);
+    pub const MROW: LocalName = LocalName::from_index(642);
+    pub const MS: LocalName = LocalName::from_index(643);
+    pub const MSCARRIES: LocalName = LocalName::from_index(644);
+    pub const MSCARRY: LocalName = LocalName::from_index(645);
+    pub const MSGROUP: LocalName = LocalName::from_index(646);
+    pub const MSLINE: LocalName = LocalName::from_index(647);
+    pub const MSPACE: LocalName = LocalName::from_index(648);
+    pub const MSQRT: LocalName = LocalName::from_index(649);
+    pub const MSROW: LocalName = LocalName::from_index(650);
+    pub const MSTACK: LocalName = LocalName::from_index(651);
+    pub const MSTYLE: LocalName = LocalName::from_index(652);
+    pub const MSUB: LocalName = LocalName::from_index(653);
+    pub const MSUBSUP: LocalName = LocalName::from_index(654);
+    pub const MSUP: LocalName = LocalName::from_index(655);
+    pub const MTABLE: LocalName = LocalName::from_index(656);
+    pub const MTD: LocalName = LocalName::from_index(657);
+    pub const MTEXT: LocalName = LocalName::from_index(658);
+    pub const MTR: LocalName = LocalName::from_index(659);
+    pub const MULTICOL: LocalName = LocalName::from_index(660);
+    pub const MULTIPLE: LocalName = LocalName::from_index(661);
+    pub const MUNDER: LocalName = LocalName::from_index(662);
+    pub const MUNDEROVER: LocalName = LocalName::from_index(663);
+    pub const MUTED: LocalName = LocalName::from_index(664);
+    pub const NAME: LocalName = LocalName::from_index(665);
+    pub const NARGS: LocalName = LocalName::from_index(666);
+    pub const NAV: LocalName = LocalName::from_index(667);
+    pub const NEQ: LocalName = LocalName::from_index(668);
+    pub const NEXTID: LocalName = LocalName::from_index(669);
+    pub const NOBR: LocalName = LocalName::from_index(670);
+    pub const NOEMBED: LocalName = LocalName::from_index(671);
+    pub const NOFRAMES: LocalName = LocalName::from_index(672);
+    pub const NOHREF: LocalName = LocalName::from_index(673);
+    pub const NOMODULE: LocalName = LocalName::from_index(674);
+    pub const NONCE: LocalName = LocalName::from_index(675);
+    pub const NONE: LocalName = LocalName::from_index(676);
+    pub const NORESIZE: LocalName = LocalName::from_index(677);
+    pub const NOSCRIPT: LocalName = LocalName::from_index(678);
+    pub const NOSHADE: LocalName = LocalName::from_index(679);
+    pub const NOT: LocalName = LocalName::from_index(680);
+    pub const NOTANUMBER: LocalName = LocalName::from_index(681);
+    pub const NOTATION: LocalName = LocalName::from_index(682);
+    pub const NOTIN: LocalName = LocalName::from_index(683);
+    pub const NOTPRSUBSET: LocalName = LocalName::from_index(684);
+    pub const NOTSUBSET: LocalName = LocalName::from_index(685);
+    pub const NOVALIDATE: LocalName = LocalName::from_index(686);
+    pub const NOWRAP: LocalName = LocalName::from_index(687);
+    pub const NUM_OCTAVES: LocalName = LocalName::from_index(688);
+    pub const NUMALIGN: LocalName = LocalName::from_index(689);
+    pub const NUMOCTAVES: LocalName = LocalName::from_index(690);
+    pub const OBJECT: LocalName = LocalName::from_index(691);
+    pub const OCCURRENCE: LocalName = LocalName::from_index(692);
+    pub const OFFSET: LocalName = LocalName::from_index(693);
+    pub const OL: LocalName = LocalName::from_index(694);
+    pub const ONABORT: LocalName = LocalName::from_index(695);
+    pub const ONAFTERPRINT: LocalName = LocalName::from_index(696);
+    pub const ONAUXCLICK: LocalName = LocalName::from_index(697);
+    pub const ONBEFOREINPUT: LocalName = LocalName::from_index(698);
+    pub const ONBEFOREMATCH: LocalName = LocalName::from_index(699);
+    pub const ONBEFOREPRINT: LocalName = LocalName::from_index(700);
+    pub const ONBEFORETOGGLE: LocalName = LocalName::from_index(701);
+    pub const ONBEFOREUNLOAD: LocalName = LocalName::from_index(702);
+    pub const ONBLUR: LocalName = LocalName::from_index(703);
+    pub const ONCANCEL: LocalName = LocalName::from_index(704);
+    pub const ONCANPLAY: LocalName = LocalName::from_index(705);
+    pub const ONCANPLAYTHROUGH: LocalName = LocalName::from_index(706);
+    pub const ONCHANGE: LocalName = LocalName::from_index(707);
+    pub const ONCLICK: LocalName = LocalName::from_index(708);
+    pub const ONCLOSE: LocalName = LocalName::from_index(709);
+    pub const ONCONTEXTLOST: LocalName = LocalName::from_index(710);
+    pub const ONCONTEXTMENU: LocalName = LocalName::from_index(711);
+    pub const ONCONTEXTRESTORED: LocalName = LocalName::from_index(712);
+    pub const ONCOPY: LocalName = LocalName::from_index(713);
+    pub const ONCUECHANGE: LocalName = LocalName::from_index(714);
+    pub const ONCUT: LocalName = LocalName::from_index(715);
+    pub const ONDBLCLICK: LocalName = LocalName::from_index(716);
+    pub const ONDRAG: LocalName = LocalName::from_index(717);
+    pub const ONDRAGEND: LocalName = LocalName::from_index(718);
+    pub const ONDRAGENTER: LocalName = LocalName::from_index(719);
+    pub const ONDRAGLEAVE: LocalName = LocalName::from_index(720);
+    pub const ONDRAGOVER: LocalName = LocalName::from_index(721);
+    pub const ONDRAGSTART: LocalName = LocalName::from_index(722);
+    pub const ONDROP: LocalName = LocalName::from_index(723);
+    pub const ONDURATIONCHANGE: LocalName = LocalName::from_index(724);
+    pub const ONEMPTIED: LocalName = LocalName::from_index(725);
+    pub const ONENDED: LocalName = LocalName::from_index(726);
+    pub const ONERROR: LocalName = LocalName::from_index(727);
+    pub const ONFOCUS: LocalName = LocalName::from_index(728);
+    pub const ONFORMDATA: LocalName = LocalName::from_index(729);
+    pub const ONHASHCHANGE: LocalName = LocalName::from_index(730);
+    pub const ONINPUT: LocalName = LocalName::from_index(731);
+    pub const ONINVALID: LocalName = LocalName::from_index(732);
+    pub const ONKEYDOWN: LocalName = LocalName::from_index(733);
+    pub const ONKEYPRESS: LocalName = LocalName::from_index(734);
+    pub const ONKEYUP: LocalName = LocalName::from_index(735);
+    pub const ONLANGUAGECHANGE: LocalName = LocalName::from_index(736);
+    pub const ONLOAD: LocalName = LocalName::from_index(737);
+    pub const ONLOADEDDATA: LocalName = LocalName::from_index(738);
+    pub const ONLOADEDMETADATA: LocalName = LocalName::from_index(739);
+    pub const ONLOADSTART: LocalName = LocalName::from_index(740);
+    pub const ONMESSAGE: LocalName = LocalName::from_index(741);
+    pub const ONMESSAGEERROR: LocalName = LocalName::from_index(742);
+    pub const ONMOUSEDOWN: LocalName = LocalName::from_index(743);
+    pub const ONMOUSEENTER: LocalName = LocalName::from_index(744);
+    pub const ONMOUSELEAVE: LocalName = LocalName::from_index(745);
+    pub const ONMOUSEMOVE: LocalName = LocalName::from_index(746);
+    pub const ONMOUSEOUT: LocalName = LocalName::from_index(747);
+    pub const ONMOUSEOVER: LocalName = LocalName::from_index(748);
+    pub const ONMOUSEUP: LocalName = LocalName::from_index(749);
+    pub const ONOFFLINE: LocalName = LocalName::from_index(750);
+    pub const ONONLINE: LocalName = LocalName::from_index(751);
+    pub const ONPAGEHIDE: LocalName = LocalName::from_index(752);
+    pub const ONPAGEREVEAL: LocalName = LocalName::from_index(753);
+    pub const ONPAGESHOW: LocalName = LocalName::from_index(754);
+    pub const ONPAGESWAP: LocalName = LocalName::from_index(755);
+    pub const ONPASTE: LocalName = LocalName::from_index(756);
+    pub const ONPAUSE: LocalName = LocalName::from_index(757);
+    pub const ONPLAY: LocalName = LocalName::from_index(758);
+    pub const ONPLAYING: LocalName = LocalName::from_index(759);
+    pub const ONPOPSTATE: LocalName = LocalName::from_index(760);
+    pub const ONPROGRESS: LocalName = LocalName::from_index(761);
+    pub const ONRATECHANGE: LocalName = LocalName::from_index(762);
+    pub const ONREJECTIONHANDLED: LocalName = LocalName::from_index(763);
+    pub const ONRESET: LocalName = LocalName::from_index(764);
+    pub const ONRESIZE: LocalName = LocalName::from_index(765);
+    pub const ONSCROLL: LocalName = LocalName::from_index(766);
+    pub const ONSCROLLEND: LocalName = LocalName::from_index(767);
+    pub const ONSECURITYPOLICYVIOLATION: LocalName = LocalName::from_index(768);
+    pub const ONSEEKED: LocalName = LocalName::from_index(769);
+    pub const ONSEEKING: LocalName = LocalName::from_index(770);
+    pub const ONSELECT: LocalName = LocalName::from_index(771);
+    pub const ONSLOTCHANGE: LocalName = LocalName::from_index(772);
+    pub const ONSTALLED: LocalName = LocalName::from_index(773);
+    pub const ONSTORAGE: LocalName = LocalName::from_index(774);
+    pub const ONSUBMIT: LocalName = LocalName::from_index(775);
+    pub const ONSUSPEND: LocalName = LocalName::from_index(776);
+    pub const ONTIMEUPDATE: LocalName = LocalName::from_index(777);
+    pub const ONTOGGLE: LocalName = LocalName::from_index(778);
+    pub const ONUNHANDLEDREJECTION: LocalName = LocalName::from_index(779);
+    pub const ONUNLOAD: LocalName = LocalName::from_index(780);
+    pub const ONVOLUMECHANGE: LocalName = LocalName::from_index(781);
+    pub const ONWAITING: LocalName = LocalName::from_index(782);
+    pub const ONWHEEL: LocalName = LocalName::from_index(783);
+    pub const OPACITY: LocalName = LocalName::from_index(784);
+    pub const OPEN: LocalName = LocalName::from_index(785);
+    pub const OPERATOR: LocalName = LocalName::from_index(786);
+    pub const OPTGROUP: LocalName = LocalName::from_index(787);
+    pub const OPTIMUM: LocalName = LocalName::from_index(788);
+    pub const OPTION: LocalName = LocalName::from_index(789);
+    pub const OR: LocalName = LocalName::from_index(790);
+    pub const ORDER: LocalName = LocalName::from_index(791);
+    pub const ORIENT: LocalName = LocalName::from_index(792);
+    pub const ORIENTATION: LocalName = LocalName::from_index(793);
+    pub const ORIGIN: LocalName = LocalName::from_index(794);
+    pub const OTHER: LocalName = LocalName::from_index(795);
+    pub const OTHERWISE: LocalName = LocalName::from_index(796);
+    pub const OUTERPRODUCT: LocalName = LocalName::from_index(797);
+    pub const OUTPUT: LocalName = LocalName::from_index(798);
+    pub const OVERFLOW: LocalName = LocalName::from_index(799);
+    pub const OVERLINE_POSITION: LocalName = LocalName::from_index(800);
+    pub const OVERLINE_THICKNESS: LocalName = LocalName::from_index(801);
+    pub const P: LocalName = LocalName::from_index(802);
+    pub const PAINT_ORDER: LocalName = LocalName::from_index(803);
+    pub const PANOSE_1: LocalName = LocalName::from_index(804);
+    pub const PARAM: LocalName = LocalName::from_index(805);
+    pub const PARTIALDIFF: LocalName = LocalName::from_index(806);
+    pub const PATH: LocalName = LocalName::from_index(807);
+    pub const PATH_LENGTH: LocalName = LocalName::from_index(808);
+    pub const PATHLENGTH: LocalName = LocalName::from_index(809);
+    pub const PATTERN: LocalName = LocalName::from_index(810);
+    pub const PATTERN_CONTENT_UNITS: LocalName = LocalName::from_index(811);
+    pub const PATTERN_TRANSFORM: LocalName = LocalName::from_index(812);
+    pub const PATTERN_UNITS: LocalName = LocalName::from_index(813);
+    pub const PATTERNCONTENTUNITS: LocalName = LocalName::from_index(814);
+    pub const PATTERNTRANSFORM: LocalName = LocalName::from_index(815);
+    pub const PATTERNUNITS: LocalName = LocalName::from_index(816);
+    pub const PI: LocalName = LocalName::from_index(817);
+    pub const PICTURE: LocalName = LocalName::from_index(818);
+    pub const PIECE: LocalName = LocalName::from_index(819);
+    pub const PIECEWISE: LocalName = LocalName::from_index(820);
+    pub const PING: LocalName = LocalName::from_index(821);
+    pub const PLACEHOLDER: LocalName = LocalName::from_index(822);
+    pub const PLAINTEXT: LocalName = LocalName::from_index(823);
+    pub const PLAYSINLINE: LocalName = LocalName::from_index(824);
+    pub const PLUS: LocalName = LocalName::from_index(825);
+    pub const POINTER_EVENTS: LocalName = LocalName::from_index(826);
+    pub const POINTS: LocalName = LocalName::from_index(827);
+    pub const POINTS_AT_X: LocalName = LocalName::from_index(828);
+    pub const POINTS_AT_Y: LocalName = LocalName::from_index(829);
+    pub const POINTS_AT_Z: LocalName = LocalName::from_index(830);
+    pub const POINTSATX: LocalName = LocalName::from_index(831);
+    pub const POINTSATY: LocalName = LocalName::from_index(832);
+    pub const POINTSATZ: LocalName = LocalName::from_index(833);
+    pub const POLYGON: LocalName = LocalName::from_index(834);
+    pub const POLYLINE: LocalName = LocalName::from_index(835);
+    pub const POPOVER: LocalName = LocalName::from_index(836);
+    pub const POPOVERTARGET: LocalName = LocalName::from_index(837);
+    pub const POPOVERTARGETACTION: LocalName = LocalName::from_index(838);
+    pub const POSITION: LocalName = LocalName::from_index(839);
+    pub const POSTER: LocalName = LocalName::from_index(840);
+    pub const POWER: LocalName = LocalName::from_index(841);
+    pub const PRE: LocalName = LocalName::from_index(842);
+    pub const PRELOAD: LocalName = LocalName::from_index(843);
+    pub const PRESERVE_ALPHA: LocalName = LocalName::from_index(844);
+    pub const PRESERVE_ASPECT_RATIO: LocalName = LocalName::from_index(845);
+    pub const PRESERVEALPHA: LocalName = LocalName::from_index(846);
+    pub const PRESERVEASPECTRATIO: LocalName = LocalName::from_index(847);
+    pub const PRIMES: LocalName = LocalName::from_index(848);
+    pub const PRIMITIVE_UNITS: LocalName = LocalName::from_index(849);
+    pub const PRIMITIVEUNITS: LocalName = LocalName::from_index(850);
+    pub const PRODUCT: LocalName = LocalName::from_index(851);
+    pub const PROFILE: LocalName = LocalName::from_index(852);
+    pub const PROGRESS: LocalName = LocalName::from_index(853);
+    pub const PROMPT: LocalName = LocalName::from_index(854);
+    pub const PRSUBSET: LocalName = LocalName::from_index(855);
+    pub const Q: LocalName = LocalName::from_index(856);
+    pub const QUOTIENT: LocalName = LocalName::from_index(857);
+    pub const R: LocalName = LocalName::from_index(858);
+    pub const RADIAL_GRADIENT: LocalName = LocalName::from_index(859);
+    pub const RADIALGRADIENT: LocalName = LocalName::from_index(860);
+    pub const RADIUS: LocalName = LocalName::from_index(861);
+    pub const RATIONALS: LocalName = LocalName::from_index(862);
+    pub const RB: LocalName = LocalName::from_index(863);
+    pub const READONLY: LocalName = LocalName::from_index(864);
+    pub const REAL: LocalName = LocalName::from_index(865);
+    pub const REALS: LocalName = LocalName::from_index(866);
+    pub const RECT: LocalName = LocalName::from_index(867);
+    pub const REF_X: LocalName = LocalName::from_index(868);
+    pub const REF_Y: LocalName = LocalName::from_index(869);
+    pub const REFERRERPOLICY: LocalName = LocalName::from_index(870);
+    pub const REFX: LocalName = LocalName::from_index(871);
+    pub const REFY: LocalName = LocalName::from_index(872);
+    pub const REL: LocalName = LocalName::from_index(873);
+    pub const RELN: LocalName = LocalName::from_index(874);
+    pub const REM: LocalName = LocalName::from_index(875);
+    pub const RENDERING_INTENT: LocalName = LocalName::from_index(876);
+    pub const REPEAT_COUNT: LocalName = LocalName::from_index(877);
+    pub const REPEAT_DUR: LocalName = LocalName::from_index(878);
+    pub const REPEATCOUNT: LocalName = LocalName::from_index(879);
+    pub const REPEATDUR: LocalName = LocalName::from_index(880);
+    pub const REQUIRED: LocalName = LocalName::from_index(881);
+    pub const REQUIRED_EXTENSIONS: LocalName = LocalName::from_index(882);
+    pub const REQUIRED_FEATURES: LocalName = LocalName::from_index(883);
+    pub const REQUIREDEXTENSIONS: LocalName = LocalName::from_index(884);
+    pub const REQUIREDFEATURES: LocalName = LocalName::from_index(885);
+    pub const RESTART: LocalName = LocalName::from_index(886);
+    pub const RESULT: LocalName = LocalName::from_index(887);
+    pub const REV: LocalName = LocalName::from_index(888);
+    pub const REVERSED: LocalName = LocalName::from_index(889);
+    pub const ROLE: LocalName = LocalName::from_index(890);
+    pub const ROOT: LocalName = LocalName::from_index(891);
+    pub const ROTATE: LocalName = LocalName::from_index(892);
+    pub const ROWALIGN: LocalName = LocalName::from_index(893);
+    pub const ROWLINES: LocalName = LocalName::from_index(894);
+    pub const ROWS: LocalName = LocalName::from_index(895);
+    pub const ROWSPACING: LocalName = LocalName::from_index(896);
+    pub const ROWSPAN: LocalName = LocalName::from_index(897);
+    pub const RP: LocalName = LocalName::from_index(898);
+    pub const RQUOTE: LocalName = LocalName::from_index(899);
+    pub const RSPACE: LocalName = LocalName::from_index(900);
+    pub const RT: LocalName = LocalName::from_index(901);
+    pub const RTC: LocalName = LocalName::from_index(902);
+    pub const RUBY: LocalName = LocalName::from_index(903);
+    pub const RULES: LocalName = LocalName::from_index(904);
+    pub const RX: LocalName = LocalName::from_index(905);
+    pub const RY: LocalName = LocalName::from_index(906);
+    pub const S: LocalName = LocalName::from_index(907);
+    pub const SAMP: LocalName = LocalName::from_index(908);
+    pub const SANDBOX: LocalName = LocalName::from_index(909);
+    pub const SCALARPRODUCT: LocalName = LocalName::from_index(910);
+    pub const SCALE: LocalName = LocalName::from_index(911);
+    pub const SCHEME: LocalName = LocalName::from_index(912);
+    pub const SCOPE: LocalName = LocalName::from_index(913);
+    pub const SCRIPT: LocalName = LocalName::from_index(914);
+    pub const SCRIPTLEVEL: LocalName = LocalName::from_index(915);
+    pub const SCRIPTMINSIZE: LocalName = LocalName::from_index(916);
+    pub const SCRIPTSIZEMULTIPLIER: LocalName = LocalName::from_index(917);
+    pub const SCROLLING: LocalName = LocalName::from_index(918);
+    pub const SDEV: LocalName = LocalName::from_index(919);
+    pub const SEARCH: LocalName = LocalName::from_index(920);
+    pub const SEC: LocalName = LocalName::from_index(921);
+    pub const SECH: LocalName = LocalName::from_index(922);
+    pub const SECTION: LocalName = LocalName::from_index(923);
+    pub const SEED: LocalName = LocalName::from_index(924);
+    pub const SELECT: LocalName = LocalName::from_index(925);
+    pub const SELECTED: LocalName = LocalName::from_index(926);
+    pub const SELECTION: LocalName = LocalName::from_index(927);
+    pub const SELECTOR: LocalName = LocalName::from_index(928);
+    pub const SEMANTICS: LocalName = LocalName::from_index(929);
+    pub const SEP: LocalName = LocalName::from_index(930);
+    pub const SEPARATOR: LocalName = LocalName::from_index(931);
+    pub const SEPARATORS: LocalName = LocalName::from_index(932);
+    pub const SET: LocalName = LocalName::from_index(933);
+    pub const SETDIFF: LocalName = LocalName::from_index(934);
+    pub const SHADOWROOTCLONABLE: LocalName = LocalName::from_index(935);
+    pub const SHADOWROOTDELEGATESFOCUS: LocalName = LocalName::from_index(936);
+    pub const SHADOWROOTMODE: LocalName = LocalName::from_index(937);
+    pub const SHADOWROOTSERIALIZABLE: LocalName = LocalName::from_index(938);
+    pub const SHAPE: LocalName = LocalName::from_index(939);
+    pub const SHAPE_RENDERING: LocalName = LocalName::from_index(940);
+    pub const SHARE: LocalName = LocalName::from_index(941);
+    pub const SHIFT: LocalName = LocalName::from_index(942);
+    pub const SHOW: LocalName = LocalName::from_index(943);
+    pub const SIDE: LocalName = LocalName::from_index(944);
+    pub const SIN: LocalName = LocalName::from_index(945);
+    pub const SINH: LocalName = LocalName::from_index(946);
+    pub const SIZE: LocalName = LocalName::from_index(947);
+    pub const SIZES: LocalName = LocalName::from_index(948);
+    pub const SLOPE: LocalName = LocalName::from_index(949);
+    pub const SLOT: LocalName = LocalName::from_index(950);
+    pub const SMALL: LocalName = LocalName::from_index(951);
+    pub const SOURCE: LocalName = LocalName::from_index(952);
+    pub const SPACE: LocalName = LocalName::from_index(953);
+    pub const SPACER: LocalName = LocalName::from_index(954);
+    pub const SPACING: LocalName = LocalName::from_index(955);
+    pub const SPAN: LocalName = LocalName::from_index(956);
+    pub const SPECULAR_CONSTANT: LocalName = LocalName::from_index(957);
+    pub const SPECULAR_EXPONENT: LocalName = LocalName::from_index(958);
+    pub const SPECULARCONSTANT: LocalName = LocalName::from_index(959);
+    pub const SPECULAREXPONENT: LocalName = LocalName::from_index(960);
+    pub const SPEED: LocalName = LocalName::from_index(961);
+    pub const SPELLCHECK: LocalName = LocalName::from_index(962);
+    pub const SPREAD_METHOD: LocalName = LocalName::from_index(963);
+    pub const SPREADMETHOD: LocalName = LocalName::from_index(964);
+    pub const SRC: LocalName = LocalName::from_index(965);
+    pub const SRCDOC: LocalName = LocalName::from_index(966);
+    pub const SRCLANG: LocalName = LocalName::from_index(967);
+    pub const SRCSET: LocalName = LocalName::from_index(968);
+    pub const STACKALIGN: LocalName = LocalName::from_index(969);
+    pub const STANDBY: LocalName = LocalName::from_index(970);
+    pub const START: LocalName = LocalName::from_index(971);
+    pub const START_OFFSET: LocalName = LocalName::from_index(972);
+    pub const STARTOFFSET: LocalName = LocalName::from_index(973);
+    pub const STD_DEVIATION: LocalName = LocalName::from_index(974);
+    pub const STDDEVIATION: LocalName = LocalName::from_index(975);
+    pub const STEMH: LocalName = LocalName::from_index(976);
+    pub const STEMV: LocalName = LocalName::from_index(977);
+    pub const STEP: LocalName = LocalName::from_index(978);
+    pub const STITCH_TILES: LocalName = LocalName::from_index(979);
+    pub const STITCHTILES: LocalName = LocalName::from_index(980);
+    pub const STOP: LocalName = LocalName::from_index(981);
+    pub const STOP_COLOR: LocalName = LocalName::from_index(982);
+    pub const STOP_OPACITY: LocalName = LocalName::from_index(983);
+    pub const STRETCHY: LocalName = LocalName::from_index(984);
+    pub const STRIKE: LocalName = LocalName::from_index(985);
+    pub const STRIKETHROUGH_POSITION: LocalName = LocalName::from_index(986);
+    pub const STRIKETHROUGH_THICKNESS: LocalName = LocalName::from_index(987);
+    pub const STRING: LocalName = LocalName::from_index(988);
+    pub const STROKE: LocalName = LocalName::from_index(989);
+    pub const STROKE_DASHARRAY: LocalName = LocalName::from_index(990);
+    pub const STROKE_DASHOFFSET: LocalName = LocalName::from_index(991);
+    pub const STROKE_LINECAP: LocalName = LocalName::from_index(992);
+    pub const STROKE_LINEJOIN: LocalName = LocalName::from_index(993);
+    pub const STROKE_MITERLIMIT: LocalName = LocalName::from_index(994);
+    pub const STROKE_OPACITY: LocalName = LocalName::from_index(995);
+    pub const STROKE_WIDTH: LocalName = LocalName::from_index(996);
+    pub const STRONG: LocalName = LocalName::from_index(997);
+    pub const STYLE: LocalName = LocalName::from_index(998);
+    pub const SUB: LocalName = LocalName::from_index(999);
+    pub const SUBSCRIPTSHIFT: LocalName = LocalName::from_index(1000);
+    pub const SUBSET: LocalName = LocalName::from_index(1001);
+    pub const SUM: LocalName = LocalName::from_index(1002);
+    pub const SUMMARY: LocalName = LocalName::from_index(1003);
+    pub const SUP: LocalName = LocalName::from_index(1004);
+    pub const SUPERSCRIPTSHIFT: LocalName = LocalName::from_index(1005);
+    pub const SURFACE_SCALE: LocalName = LocalName::from_index(1006);
+    pub const SURFACESCALE: LocalName = LocalName::from_index(1007);
+    pub const SVG: LocalName = LocalName::from_index(1008);
+    pub const SWITCH: LocalName = LocalName::from_index(1009);
+    pub const SYMBOL: LocalName = LocalName::from_index(1010);
+    pub const SYMMETRIC: LocalName = LocalName::from_index(1011);
+    pub const SYSTEM_LANGUAGE: LocalName = LocalName::from_index(1012);
+    pub const SYSTEMLANGUAGE: LocalName = LocalName::from_index(1013);
+    pub const TABINDEX: LocalName = LocalName::from_index(1014);
+    pub const TABLE: LocalName = LocalName::from_index(1015);
+    pub const TABLE_VALUES: LocalName = LocalName::from_index(1016);
+    pub const TABLEVALUES: LocalName = LocalName::from_index(1017);
+    pub const TAN: LocalName = LocalName::from_index(1018);
+    pub const TANH: LocalName = LocalName::from_index(1019);
+    pub const TARGET: LocalName = LocalName::from_index(1020);
+    pub const TARGET_X: LocalName = LocalName::from_index(1021);
+    pub const TARGET_Y: LocalName = LocalName::from_index(1022);
+    pub const TARGETX: LocalName = LocalName::from_index(1023);
+    pub const TARGETY: LocalName = LocalName::from_index(1024);
+    pub const TBODY: LocalName = LocalName::from_index(1025);
+    pub const TD: LocalName = LocalName::from_index(1026);
+    pub const TEMPLATE: LocalName = LocalName::from_index(1027);
+    pub const TENDSTO: LocalName = LocalName::from_index(1028);
+    pub const TEXT: LocalName = LocalName::from_index(1029);
+    pub const TEXT_ANCHOR: LocalName = LocalName::from_index(1030);
+    pub const TEXT_DECORATION: LocalName = LocalName::from_index(1031);
+    pub const TEXT_RENDERING: LocalName = LocalName::from_index(1032);
+    pub const TEXT_LENGTH: LocalName = LocalName::from_index(1033);
+    pub const TEXT_PATH: LocalName = LocalName::from_index(1034);
+    pub const TEXTAREA: LocalName = LocalName::from_index(1035);
+    pub const TEXTLENGTH: LocalName = LocalName::from_index(1036);
+    pub const TEXTPATH: LocalName = LocalName::from_index(1037);
+    pub const TFOOT: LocalName = LocalName::from_index(1038);
+    pub const TH: LocalName = LocalName::from_index(1039);
+    pub const THEAD: LocalName = LocalName::from_index(1040);
+    pub const TIME: LocalName = LocalName::from_index(1041);
+    pub const TIMES: LocalName = LocalName::from_index(1042);
+    pub const TITLE: LocalName = LocalName::from_index(1043);
+    pub const TO: LocalName = LocalName::from_index(1044);
+    pub const TR: LocalName = LocalName::from_index(1045);
+    pub const TRACK: LocalName = LocalName::from_index(1046);
+    pub const TRANSFORM: LocalName = LocalName::from_index(1047);
+    pub const TRANSFORM_ORIGIN: LocalName = LocalName::from_index(1048);
+    pub const TRANSLATE: LocalName = LocalName::from_index(1049);
+    pub const TRANSPOSE: LocalName = LocalName::from_index(1050);
+    pub const TREF: LocalName = LocalName::from_index(1051);
+    pub const TRUE: LocalName = LocalName::from_index(1052);
+    pub const TSPAN: LocalName = LocalName::from_index(1053);
+    pub const TT: LocalName = LocalName::from_index(1054);
+    pub const TYPE: LocalName = LocalName::from_index(1055);
+    pub const U: LocalName = LocalName::from_index(1056);
+    pub const U1: LocalName = LocalName::from_index(1057);
+    pub const U2: LocalName = LocalName::from_index(1058);
+    pub const UL: LocalName = LocalName::from_index(1059);
+    pub const UNDERLINE_POSITION: LocalName = LocalName::from_index(1060);
+    pub const UNDERLINE_THICKNESS: LocalName = LocalName::from_index(1061);
+    pub const UNICODE: LocalName = LocalName::from_index(1062);
+    pub const UNICODE_BIDI: LocalName = LocalName::from_index(1063);
+    pub const UNICODE_RANGE: LocalName = LocalName::from_index(1064);
+    pub const UNION: LocalName = LocalName::from_index(1065);
+    pub const UNITS_PER_EM: LocalName = LocalName::from_index(1066);
+    pub const UPLIMIT: LocalName = LocalName::from_index(1067);
+    pub const USE: LocalName = LocalName::from_index(1068);
+    pub const USEMAP: LocalName = LocalName::from_index(1069);
+    pub const V_ALPHABETIC: LocalName = LocalName::from_index(1070);
+    pub const V_HANGING: LocalName = LocalName::from_index(1071);
+    pub const V_IDEOGRAPHIC: LocalName = LocalName::from_index(1072);
+    pub const V_MATHEMATICAL: LocalName = LocalName::from_index(1073);
+    pub const VALIGN: LocalName = LocalName::from_index(1074);
+    pub const VALUE: LocalName = LocalName::from_index(1075);
+    pub const VALUES: LocalName = LocalName::from_index(1076);
+    pub const VALUETYPE: LocalName = LocalName::from_index(1077);
+    pub const VAR: LocalName = LocalName::from_index(1078);
+    pub const VARIANCE: LocalName = LocalName::from_index(1079);
+    pub const VECTOR: LocalName = LocalName::from_index(1080);
+    pub const VECTOR_EFFECT: LocalName = LocalName::from_index(1081);
+    pub const VECTORPRODUCT: LocalName = LocalName::from_index(1082);
+    pub const VERSION: LocalName = LocalName::from_index(1083);
+    pub const VERT_ADV_Y: LocalName = LocalName::from_index(1084);
+    pub const VERT_ORIGIN_X: LocalName = LocalName::from_index(1085);
+    pub const VERT_ORIGIN_Y: LocalName = LocalName::from_index(1086);
+    pub const VIDEO: LocalName = LocalName::from_index(1087);
+    pub const VIEW: LocalName = LocalName::from_index(1088);
+    pub const VIEW_BOX: LocalName = LocalName::from_index(1089);
+    pub const VIEW_TARGET: LocalName = LocalName::from_index(1090);
+    pub const VIEWBOX: LocalName = LocalName::from_index(1091);
+    pub const VIEWTARGET: LocalName = LocalName::from_index(1092);
+    pub const VISIBILITY: LocalName = LocalName::from_index(1093);
+    pub const VKERN: LocalName = LocalName::from_index(1094);
+    pub const VLINK: LocalName = LocalName::from_index(1095);
+    pub const VOFFSET: LocalName = LocalName::from_index(1096);
+    pub const VSPACE: LocalName = LocalName::from_index(1097);
+    pub const WBR: LocalName = LocalName::from_index(1098);
+    pub const WIDTH: LocalName = LocalName::from_index(1099);
+    pub const WIDTHS: LocalName = LocalName::from_index(1100);
+    pub const WORD_SPACING: LocalName = LocalName::from_index(1101);
+    pub const WRAP: LocalName = LocalName::from_index(1102);
+    pub const WRITING_MODE: LocalName = LocalName::from_index(1103);
+    pub const WRITINGSUGGESTIONS: LocalName = LocalName::from_index(1104);
+    pub const X: LocalName = LocalName::from_index(1105);
+    pub const X_HEIGHT: LocalName = LocalName::from_index(1106);
+    pub const X1: LocalName = LocalName::from_index(1107);
+    pub const X2: LocalName = LocalName::from_index(1108);
+    pub const X_CHANNEL_SELECTOR: LocalName = LocalName::from_index(1109);
+    pub const XCHANNELSELECTOR: LocalName = LocalName::from_index(1110);
+    pub const XLINK: LocalName = LocalName::from_index(1111);
+    pub const XLINK_ACTUATE: LocalName = LocalName::from_index(1112);
+    pub const XLINK_ARCROLE: LocalName = LocalName::from_index(1113);
+    pub const XLINK_HREF: LocalName = LocalName::from_index(1114);
+    pub const XLINK_ROLE: LocalName = LocalName::from_index(1115);
+    pub const XLINK_SHOW: LocalName = LocalName::from_index(1116);
+    pub const XLINK_TITLE: LocalName = LocalName::from_index(1117);
+    pub const XLINK_TYPE: LocalName = LocalName::from_index(1118);
+    pub const XML_LANG: LocalName = LocalName::from_index(1119);
+    pub const XML_SPACE: LocalName = LocalName::from_index(1120);
+    pub const XMLNS: LocalName = LocalName::from_index(1121);
+    pub const XMLNS_XLINK: LocalName = LocalName::from_index(1122);
+    pub const XMP: LocalName = LocalName::from_index(1123);
+    pub const XOR: LocalName = LocalName::from_index(1124);
+    pub const XREF: LocalName = LocalName::from_index(1125);
+    pub const Y: LocalName = LocalName::from_index(1126);
+    pub const Y1: LocalName = LocalName::from_index(1127);
+    pub const Y2: LocalName = LocalName::from_index(1128);
+    pub const Y_CHANNEL_SELECTOR: LocalName = LocalName::from_index(1129);
+    pub const YCHANNELSELECTOR: LocalName = LocalName::from_index(1130);
+    pub const Z: LocalName = LocalName::from_index(1131);
+    pub const ZOOM_AND_PAN: LocalName = LocalName::from_index(1132);
+    pub const ZOOMANDPAN: LocalName = LocalName::from_index(1133);
+}
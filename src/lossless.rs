@@ -0,0 +1,430 @@
+use std::ops::Deref;
+
+use crate::error::ParseError;
+use crate::limits::Limits;
+use crate::options::TokenizerOptions;
+use crate::position::Span;
+use crate::state::State;
+use crate::template::TemplateDelimiter;
+use crate::token::Token;
+use crate::tokenizer::BorrowedTokenizer;
+
+// A token together with the source it was read from, for tools which have to write the document
+// back out with only their own edits in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LosslessToken<'a> {
+    pub token: Token<'a>,
+    pub span: Span,
+    // The source from the end of the previous token to the end of this one. That is the span
+    // plus whatever came before it without making a token of its own, like "</>", the
+    // "<![CDATA[" and "]]>" around a CDATA section, or a tag cut off by the end of the input
+    // (which ends up in the EOF token). Concatenating raw over all tokens gives back the input.
+    pub raw: &'a str,
+    // the tag exactly as it was written, for start and end tags.
+    pub tag: Option<RawTag<'a>>,
+}
+
+// A start or end tag as it was written. Names keep their casing and values are neither decoded
+// nor stripped of CRs. Unlike in the token, attributes which repeat an earlier name are kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawTag<'a> {
+    pub name: &'a str,
+    pub attributes: Vec<RawAttribute<'a>>,
+    // whitespace and slashes between the last attribute (or the name) and the ">".
+    pub trailing: &'a str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawAttribute<'a> {
+    // whitespace and slashes between the previous attribute (or the tag name) and this one.
+    pub before: &'a str,
+    pub name: &'a str,
+    pub value: Option<RawValue<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawValue<'a> {
+    // the "=" and any whitespace around it.
+    pub equals: &'a str,
+    pub quote: Quote,
+    // without the quotes.
+    pub value: &'a str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quote {
+    Double,
+    Single,
+    Unquoted,
+}
+
+impl<'a> RawTag<'a> {
+    // Splits up the source of a tag token, from its "<" to its ">". This follows the tag states
+    // of the tokenizer but only ever sees tags the tokenizer already read, so it never has to
+    // deal with the end of the input.
+    pub fn parse(source: &'a str) -> Self {
+        RawTag::parse_with_templates(source, &[])
+    }
+
+    // Same as parse, for a tokenizer with template delimiters set: like there, a template in an
+    // attribute value is part of the value even where it has quotes, whitespace or a ">" in it.
+    pub fn parse_with_templates(source: &'a str, templates: &[TemplateDelimiter]) -> Self {
+        let without_end = source.strip_suffix('>').unwrap_or(source);
+        let inner = without_end.strip_prefix("</").or_else(|| without_end.strip_prefix('<'));
+        let inner = inner.unwrap_or(without_end);
+        // inner with the ">" after it, a closing delimiter can end in one.
+        let text = &source[without_end.len() - inner.len()..];
+        let templates: Vec<_> = templates.iter().filter(|delimiter| !delimiter.open.is_empty()).collect();
+        let value_end = |from, ends: &dyn Fn(char) -> bool| find_value_end(text, inner.len(), from, &templates, ends);
+
+        let name_end = find_from(inner, 0, |c| is_whitespace(c) || c == '/');
+        let mut tag = RawTag {
+            name: &inner[..name_end],
+            attributes: Vec::new(),
+            trailing: "",
+        };
+
+        let mut i = name_end;
+        loop {
+            let before_start = i;
+            i = find_from(inner, i, |c| !is_whitespace(c) && c != '/');
+            if i == inner.len() {
+                tag.trailing = &inner[before_start..];
+                return tag;
+            }
+
+            // the first character belongs to the name even when it is a "=".
+            let name_start = i;
+            let first = inner[i..].chars().next().map_or(1, char::len_utf8);
+            i = find_from(inner, i + first, |c| is_whitespace(c) || c == '/' || c == '=');
+            let name = &inner[name_start..i];
+
+            let after_name = i;
+            let equals = find_from(inner, i, |c| !is_whitespace(c));
+            let value = if inner[equals..].starts_with('=') {
+                let value_start = find_from(inner, equals + 1, |c| !is_whitespace(c));
+                let equals = &inner[after_name..value_start];
+                let (quote, value, end) = match inner[value_start..].chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let value_end = value_end(value_start + 1, &|c| c == quote);
+                        let quote = if quote == '"' { Quote::Double } else { Quote::Single };
+                        (quote, &inner[value_start + 1..value_end], (value_end + 1).min(inner.len()))
+                    }
+                    _ => {
+                        let value_end = value_end(value_start, &is_whitespace);
+                        (Quote::Unquoted, &inner[value_start..value_end], value_end)
+                    }
+                };
+                i = end;
+                Some(RawValue { equals, quote, value })
+            } else {
+                None
+            };
+
+            tag.attributes.push(RawAttribute {
+                before: &inner[before_start..name_start],
+                name,
+                value,
+            });
+        }
+    }
+}
+
+// Hands out tokens with their raw source. It only works on a source which is there in full, and
+// otherwise behaves like the BorrowedTokenizer it wraps, which it derefs to.
+pub struct LosslessTokenizer<'a> {
+    source: &'a str,
+    tokenizer: BorrowedTokenizer<'a>,
+    // the tokenizer's, to split up tags the same way it did.
+    template_delimiters: Vec<TemplateDelimiter>,
+    // where the raw source of the next token starts.
+    raw_start: usize,
+}
+
+impl<'a> LosslessTokenizer<'a> {
+    pub fn new(source: &'a str) -> Self {
+        LosslessTokenizer::with_options(source, TokenizerOptions::default())
+    }
+
+    // Same as new, with the source checked against limits too. Tokens and source that input_size
+    // cut off end up in the raw source of the EOF token, or of no token when Fail stopped it.
+    pub fn with_limits(source: &'a str, limits: Limits) -> Self {
+        LosslessTokenizer::with_options(source, TokenizerOptions { limits, ..TokenizerOptions::default() })
+    }
+
+    pub fn with_options(source: &'a str, options: TokenizerOptions) -> Self {
+        LosslessTokenizer {
+            source,
+            template_delimiters: options.template_delimiters.clone(),
+            tokenizer: BorrowedTokenizer::with_options(source, options),
+            raw_start: 0,
+        }
+    }
+
+    pub fn set_state(&mut self, state: State) {
+        self.tokenizer.set_state(state);
+    }
+
+    pub fn set_last_start_tag_name(&mut self, name: Option<&str>) {
+        self.tokenizer.set_last_start_tag_name(name);
+    }
+
+    pub fn set_in_foreign_content(&mut self, in_foreign_content: bool) {
+        self.tokenizer.set_in_foreign_content(in_foreign_content);
+    }
+
+    pub fn errors(&self) -> &[(ParseError, Span)] {
+        self.tokenizer.errors()
+    }
+
+    pub fn take_errors(&mut self) -> Vec<(ParseError, Span)> {
+        self.tokenizer.take_errors()
+    }
+}
+
+impl<'a> Iterator for LosslessTokenizer<'a> {
+    type Item = LosslessToken<'a>;
+
+    fn next(&mut self) -> Option<LosslessToken<'a>> {
+        let (token, span) = self.tokenizer.next_spanned()?;
+        // the EOF token takes whatever is left, which is only ever input that made no token.
+        let end = match token {
+            Token::EOF => self.source.len(),
+            _ => span.end.offset,
+        };
+        let raw = &self.source[self.raw_start..end];
+        self.raw_start = end;
+
+        let tag = match token {
+            Token::StartTag { .. } | Token::EndTag { .. } => {
+                let source = &self.source[span.start.offset..span.end.offset];
+                Some(RawTag::parse_with_templates(source, &self.template_delimiters))
+            }
+            _ => None,
+        };
+        Some(LosslessToken { token, span, raw, tag })
+    }
+}
+
+impl<'a> Deref for LosslessTokenizer<'a> {
+    type Target = BorrowedTokenizer<'a>;
+
+    fn deref(&self) -> &BorrowedTokenizer<'a> {
+        &self.tokenizer
+    }
+}
+
+// the tokenizer sees a CR as the LF it gets turned into.
+fn is_whitespace(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\x0C' | '\r' | ' ')
+}
+
+// the offset of the first character at or after from that matches, or the end of text.
+fn find_from(text: &str, from: usize, matches: impl Fn(char) -> bool) -> usize {
+    text[from..].find(matches).map_or(text.len(), |i| from + i)
+}
+
+// Where an attribute value that starts at from in text ends, at most at limit. Templates are skipped
+// over like the tokenizer does, an opening delimiter that is never closed is ordinary text.
+fn find_value_end(
+    text: &str,
+    limit: usize,
+    from: usize,
+    templates: &[&TemplateDelimiter],
+    ends: &dyn Fn(char) -> bool,
+) -> usize {
+    let mut i = from;
+    while i < limit {
+        let rest = &text[i..];
+        if let Some(delimiter) = templates.iter().find(|delimiter| rest.starts_with(&delimiter.open))
+            && let Some(close) = rest[delimiter.open.len()..].find(&delimiter.close)
+        {
+            i = (i + delimiter.open.len() + close + delimiter.close.len()).min(limit);
+            continue;
+        }
+        let c = rest.chars().next().unwrap();
+        if ends(c) {
+            return i;
+        }
+        i += c.len_utf8();
+    }
+    limit
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::limits::{Limit, LimitBehavior};
+
+    // the tag written back out from its parts.
+    fn write_tag(tag: &RawTag<'_>, end_tag: bool) -> String {
+        let mut source = String::from(if end_tag { "</" } else { "<" });
+        source.push_str(tag.name);
+        for attribute in &tag.attributes {
+            source.push_str(attribute.before);
+            source.push_str(attribute.name);
+            if let Some(value) = &attribute.value {
+                let quote = match value.quote {
+                    Quote::Double => "\"",
+                    Quote::Single => "'",
+                    Quote::Unquoted => "",
+                };
+                source.push_str(value.equals);
+                source.push_str(quote);
+                source.push_str(value.value);
+                source.push_str(quote);
+            }
+        }
+        source.push_str(tag.trailing);
+        source.push('>');
+        source
+    }
+
+    fn attribute<'a>(before: &'a str, name: &'a str, value: Option<(&'a str, Quote, &'a str)>) -> RawAttribute<'a> {
+        RawAttribute {
+            before,
+            name,
+            value: value.map(|(equals, quote, value)| RawValue { equals, quote, value }),
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        let inputs = [
+            "<!DOCTYPE html>\r\n<html lang=en>\r<head><title>a &amp b &notit; &#x80;</title></head>",
+            "<p CLASS = \"a\r\nb\" class='c' data-x=y/z DISABLED/ >text\0</p \t>",
+            "<!-- a -- b --!><!--><!----><!><!DOCTYPE><?php echo 1 ?></>x</ y>",
+            "<svg><![CDATA[ <a> ]]></svg><math><![CDATA[x",
+            "<script>if (a < b && c) { document.write('</p>') }</script><textarea>&lt;</textarea>",
+            "<a b=\"unterminated",
+            "<a b='1'c=\"2\"d=3 =e f g= />",
+            "</",
+            "\u{FEFF}\u{E9}<\u{65E5} \u{1F600}=\u{FDD0}>",
+        ];
+        for input in inputs {
+            let mut tokenizer = LosslessTokenizer::new(input);
+            let mut raw = String::new();
+            while let Some(token) = tokenizer.next() {
+                raw.push_str(token.raw);
+                // the raw source ends where the token does.
+                assert!(token.raw.ends_with(&input[token.span.start.offset..token.span.end.offset]));
+                if let Some(tag) = &token.tag {
+                    let end_tag = matches!(token.token, Token::EndTag { .. });
+                    let source = &input[token.span.start.offset..token.span.end.offset];
+                    assert_eq!(write_tag(tag, end_tag), source);
+                }
+                match &token.token {
+                    Token::StartTag { tag_name, .. } if matches!(&**tag_name, "svg" | "math") => {
+                        tokenizer.set_in_foreign_content(true);
+                    }
+                    Token::StartTag { tag_name, .. } if tag_name == "script" => tokenizer.set_state(State::ScriptData),
+                    Token::StartTag { tag_name, .. } if tag_name == "textarea" => tokenizer.set_state(State::RCData),
+                    _ => {}
+                }
+            }
+            assert_eq!(raw, input);
+        }
+    }
+
+    #[test]
+    fn templates_in_attribute_values() {
+        let input = "<a title=\"{{ \"x\" }}\" b=1 c={{ x y }} d='{% '>' %}'e={{ f }}>";
+        let options = TokenizerOptions {
+            template_delimiters: TemplateDelimiter::common(),
+            ..TokenizerOptions::default()
+        };
+        let tags: Vec<_> = LosslessTokenizer::with_options(input, options).filter_map(|token| token.tag).collect();
+        assert_eq!(
+            tags[0].attributes,
+            [
+                attribute(" ", "title", Some(("=", Quote::Double, "{{ \"x\" }}"))),
+                attribute(" ", "b", Some(("=", Quote::Unquoted, "1"))),
+                attribute(" ", "c", Some(("=", Quote::Unquoted, "{{ x y }}"))),
+                attribute(" ", "d", Some(("=", Quote::Single, "{% '>' %}"))),
+                attribute("", "e", Some(("=", Quote::Unquoted, "{{ f }}"))),
+            ]
+        );
+        // without the delimiters it is split up the way the tokenizer does without them.
+        let tag = RawTag::parse("<a c={{ x y }}>");
+        let names: Vec<_> = tag.attributes.iter().map(|attribute| attribute.name).collect();
+        assert_eq!(names, ["c", "x", "y", "}}"]);
+        // an opener that is never closed is part of the value as ordinary text.
+        let options = TokenizerOptions {
+            template_delimiters: TemplateDelimiter::common(),
+            ..TokenizerOptions::default()
+        };
+        let tag = LosslessTokenizer::with_options("<a b=\"{{ c\" d>", options).find_map(|token| token.tag).unwrap();
+        assert_eq!(
+            tag.attributes,
+            [attribute(" ", "b", Some(("=", Quote::Double, "{{ c"))), attribute(" ", "d", None)]
+        );
+    }
+
+    #[test]
+    fn limits_apply_to_the_source() {
+        let limits = Limits {
+            input_size: Some(Limit::new(6, LimitBehavior::Truncate)),
+            ..Limits::default()
+        };
+        let tokens: Vec<_> = LosslessTokenizer::with_limits("<p>ab<i>cd</i>", limits).collect();
+        let raw: Vec<_> = tokens.iter().map(|token| token.raw).collect();
+        // what was cut off is left to the EOF token, so the source still round trips.
+        assert_eq!(raw, ["<p>", "ab<", "i>cd</i>"]);
+        assert_eq!(tokens[1].token, Token::Character { data: "ab<".into() });
+        assert_eq!(tokens[2].token, Token::EOF);
+    }
+
+    #[test]
+    fn quoting() {
+        assert_eq!(
+            RawTag::parse("<a b=\"x 'y'\" c='say \"hi\"' d=x\"y'z e=\"a>b\">"),
+            RawTag {
+                name: "a",
+                attributes: vec![
+                    attribute(" ", "b", Some(("=", Quote::Double, "x 'y'"))),
+                    attribute(" ", "c", Some(("=", Quote::Single, "say \"hi\""))),
+                    attribute(" ", "d", Some(("=", Quote::Unquoted, "x\"y'z"))),
+                    attribute(" ", "e", Some(("=", Quote::Double, "a>b"))),
+                ],
+                trailing: "",
+            }
+        );
+        // empty values, whitespace around the "=", and a quote right after a quoted value.
+        assert_eq!(
+            RawTag::parse("<a b=\"\" c = '' d=''e=\"1\"f\r\n=\r\ng >"),
+            RawTag {
+                name: "a",
+                attributes: vec![
+                    attribute(" ", "b", Some(("=", Quote::Double, ""))),
+                    attribute(" ", "c", Some((" = ", Quote::Single, ""))),
+                    attribute(" ", "d", Some(("=", Quote::Single, ""))),
+                    attribute("", "e", Some(("=", Quote::Double, "1"))),
+                    attribute("", "f", Some(("\r\n=\r\n", Quote::Unquoted, "g"))),
+                ],
+                trailing: " ",
+            }
+        );
+        // casing and repeated names are kept, "=" can start a name, slashes separate.
+        assert_eq!(
+            RawTag::parse("</DIV =x HREF=1/href=/a/ b c=/>"),
+            RawTag {
+                name: "DIV",
+                attributes: vec![
+                    attribute(" ", "=x", None),
+                    attribute(" ", "HREF", Some(("=", Quote::Unquoted, "1/href=/a/"))),
+                    attribute(" ", "b", None),
+                    attribute(" ", "c", Some(("=", Quote::Unquoted, "/"))),
+                ],
+                trailing: "",
+            }
+        );
+        assert_eq!(
+            RawTag::parse("<br/ / >"),
+            RawTag {
+                name: "br",
+                attributes: Vec::new(),
+                trailing: "/ / ",
+            }
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use crate::limits::Limits;
+use crate::template::TemplateDelimiter;
+
+// The settings a tokenizer keeps for the whole document, given when it is made, which is the only
+// way to set them on a BorrowedTokenizer or LosslessTokenizer. The state a tree builder switches
+// in between tokens is set on the tokenizer itself. Everything is off by default.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TokenizerOptions {
+    // see Tokenizer::set_split_leading_whitespace.
+    pub split_leading_whitespace: bool,
+    // see Tokenizer::set_processing_instructions.
+    pub processing_instructions: bool,
+    // see Tokenizer::set_template_delimiters.
+    pub template_delimiters: Vec<TemplateDelimiter>,
+    pub limits: Limits,
+}
@@ -0,0 +1,89 @@
+use std::fmt;
+
+// A location in the input. The offset is in bytes, line and column start at 1 and the column
+// counts characters, not bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Position {
+    pub fn start() -> Self {
+        Position {
+            offset: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    // the position right after c, if c starts at self.
+    pub fn advance(self, c: char) -> Self {
+        if c == '\n' {
+            Position {
+                offset: self.offset + c.len_utf8(),
+                line: self.line + 1,
+                column: 1,
+            }
+        } else {
+            Position {
+                offset: self.offset + c.len_utf8(),
+                line: self.line,
+                column: self.column + 1,
+            }
+        }
+    }
+
+    // the position right after text, if text starts at self and has no CR in it.
+    pub fn advance_str(self, text: &str) -> Self {
+        let bytes = text.as_bytes();
+        // continuation bytes are the only ones that do not start a character.
+        let count_chars = |bytes: &[u8]| bytes.iter().filter(|&&b| (b & 0xC0) != 0x80).count();
+        match bytes.iter().rposition(|&b| b == b'\n') {
+            Some(last_newline) => Position {
+                offset: self.offset + bytes.len(),
+                line: self.line + bytes.iter().filter(|&&b| b == b'\n').count(),
+                column: 1 + count_chars(&bytes[last_newline + 1..]),
+            },
+            None => Position {
+                offset: self.offset + bytes.len(),
+                line: self.line,
+                column: self.column + count_chars(bytes),
+            },
+        }
+    }
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Position::start()
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+// start..end, end is exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Span {
+    pub fn new(start: Position, end: Position) -> Self {
+        Span { start, end }
+    }
+
+    pub fn len(&self) -> usize {
+        self.end.offset - self.start.offset
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,160 @@
+// Finding the end of a run of text the text states can emit all at once, without going through
+// the state one character at a time.
+use memchr::{memchr, memchr2, memchr3};
+
+use crate::input::{is_control, is_noncharacter};
+
+// The length of the text up to the first of the special bytes, at most two of them, a CR, or a
+// character the input stream reports as a control or noncharacter. NULL counts as a control, the
+// states report it. The special bytes have to be ASCII.
+pub(crate) fn text_len(text: &str, special: &[u8]) -> usize {
+    let bytes = text.as_bytes();
+    let len = match *special {
+        [a, b] => memchr3(a, b, b'\r', bytes),
+        [a] => memchr2(a, b'\r', bytes),
+        [] => memchr(b'\r', bytes),
+        _ => panic!("at most two special bytes"),
+    }
+    .unwrap_or(bytes.len());
+    checked_len(&text[..len])
+}
+
+// how many bytes checked_len looks at in one go.
+const BLOCK: usize = 32;
+
+// the length of the text up to the first control or noncharacter.
+fn checked_len(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    loop {
+        // most text has none of the suspect bytes, skip a block at a time while that holds. The
+        // lead bytes are common in text which isn't English, no-break spaces start with 0xC2, so
+        // a block which has one is looked at again together with the bytes after it. The window
+        // has two bytes past the block for that.
+        let suspect = match bytes.get(i..i + BLOCK + 2) {
+            Some(window) => {
+                let window: &[u8; BLOCK + 2] = window.try_into().unwrap();
+                let in_window = |j: usize| is_suspect(window[j], window[j + 1], window[j + 2]);
+                if !window[..BLOCK].iter().fold(false, |found, &b| found | is_suspect_byte(b))
+                    || !(0..BLOCK).fold(false, |found, j| found | in_window(j))
+                {
+                    i += BLOCK;
+                    continue;
+                }
+                i + (0..BLOCK).position(in_window).unwrap()
+            }
+            None => {
+                let at = |j: usize| bytes.get(j).copied().unwrap_or(0);
+                match (i..bytes.len()).find(|&j| is_suspect(bytes[j], at(j + 1), at(j + 2))) {
+                    Some(j) => j,
+                    None => return bytes.len(),
+                }
+            }
+        };
+        i = suspect;
+        if bytes[i] < 0x80 {
+            return i;
+        }
+        let c = text[i..].chars().next().unwrap();
+        let code = c as u32;
+        if is_control(code) || is_noncharacter(code) {
+            return i;
+        }
+        i += c.len_utf8();
+    }
+}
+
+// The bytes which can start a character the input stream has to look at: CR, the controls, and
+// the lead bytes of the only multi-byte characters which can be controls (U+0080 to U+009F) or
+// noncharacters (U+FDD0 and up). Written without branches, so that looking at a block of bytes
+// gets vectorized.
+fn is_suspect_byte(b: u8) -> bool {
+    (b < 0x20) & (b != 0x09) & (b != 0x0A) & (b != 0x0C) | (b == 0x7F) | (b == 0xC2) | (b >= 0xEF)
+}
+
+// The same, also looking at the two bytes after a lead byte: C2 80 to C2 9F are the C1 controls,
+// EF B7 starts U+FDC0 to U+FDFF and EF BF U+FFC0 to U+FFFF, and a four byte character ending in a
+// noncharacter has BF as its third byte. Those still have to be decoded to check, but no-break
+// spaces, quotation marks and U+FEFF aren't stopped at any more.
+fn is_suspect(b: u8, next: u8, after: u8) -> bool {
+    (b < 0x20) & (b != 0x09) & (b != 0x0A) & (b != 0x0C)
+        | (b == 0x7F)
+        | (b == 0xC2) & (next < 0xA0)
+        | (b == 0xEF) & ((next == 0xB7) | (next == 0xBF))
+        | (b >= 0xF0) & (after == 0xBF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::text_len;
+
+    #[test]
+    fn stops_at_the_special_bytes_and_cr() {
+        assert_eq!(text_len("abc<def", b"<&"), 3);
+        assert_eq!(text_len("abc&def<", b"<&"), 3);
+        assert_eq!(text_len("abc&def<", b"<"), 7);
+        assert_eq!(text_len("ab\r\ncd", b"<&"), 2);
+        assert_eq!(text_len("ab\r\ncd", b""), 2);
+        assert_eq!(text_len("abc", b"<&"), 3);
+        assert_eq!(text_len("<", b"<"), 0);
+    }
+
+    #[test]
+    fn stops_at_controls_and_noncharacters() {
+        assert_eq!(text_len("ab\0cd", b"<&"), 2);
+        assert_eq!(text_len("ab\u{1}cd", b""), 2);
+        assert_eq!(text_len("a\u{7F}", b"<"), 1);
+        assert_eq!(text_len("a\u{80}", b"<"), 1);
+        assert_eq!(text_len("a\u{9F}", b"<"), 1);
+        assert_eq!(text_len("a\u{FDD0}", b"<"), 1);
+        assert_eq!(text_len("a\u{FDEF}", b"<"), 1);
+        assert_eq!(text_len("a\u{FFFE}", b"<"), 1);
+        assert_eq!(text_len("a\u{FFFF}", b"<"), 1);
+        assert_eq!(text_len("a\u{1FFFE}", b"<"), 1);
+        assert_eq!(text_len("a\u{10FFFF}", b"<"), 1);
+    }
+
+    #[test]
+    fn runs_over_other_multibyte_characters() {
+        // tab, LF and FF are whitespace, not controls the input stream reports.
+        assert_eq!(text_len("a\tb\nc\u{C}d", b"<&"), 7);
+        // U+00A0 and U+00A9 share their lead byte with the C1 controls.
+        assert_eq!(text_len("a\u{A0}b\u{A9}c", b"<&"), 7);
+        // U+FDCF, U+FDF0 and U+FFFD share theirs with the noncharacters.
+        assert_eq!(text_len("\u{FDCF}\u{FDF0}\u{FFFD}\u{FEFF}<", b"<&"), 12);
+        assert_eq!(text_len("é日本語😀<", b"<&"), 15);
+    }
+
+    #[test]
+    fn finds_suspect_bytes_anywhere_in_a_chunk() {
+        // long enough to go through eight bytes at a time, with the character in every position.
+        for c in ['\0', '\u{B}', '\u{8}', '\u{7F}', '\u{85}', '\u{FDD0}', '\u{FFFF}', '\u{10FFFE}'] {
+            for before in 0..16 {
+                for padding in ["a", "\t", "\n", "\u{C}", " "] {
+                    let text = format!("{}{}{}", padding.repeat(before), c, "b".repeat(16));
+                    assert_eq!(text_len(&text, b"<&"), before * padding.len(), "{:?}", text);
+                }
+            }
+        }
+        let text = "\t\n\u{C}é\u{A0}日本語 ".repeat(8);
+        assert_eq!(text_len(&text, b"<&"), text.len());
+    }
+
+    #[test]
+    fn lead_bytes_are_checked_with_the_bytes_after_them() {
+        // characters sharing a lead byte, and the bytes after it, with a control or noncharacter.
+        let harmless = ['\u{A0}', '\u{AB}', '\u{FDCF}', '\u{FDF0}', '\u{FEFF}', '\u{FFFD}', '\u{1FFBF}', '\u{10FFFD}'];
+        let stops = ['\u{80}', '\u{9F}', '\u{FDD0}', '\u{FDEF}', '\u{FFFE}', '\u{1FFFF}', '\u{10FFFE}'];
+        // in a block, and in the last few bytes which don't fill one, with the block edge anywhere.
+        for before in 0..40 {
+            for c in harmless {
+                let text = format!("{}{}{}", "a".repeat(before), c, "b".repeat(before % 3));
+                assert_eq!(text_len(&text, b"<&"), text.len(), "{:?}", text);
+            }
+            for c in stops {
+                let text = format!("{}\u{A0}{}{}", "a".repeat(before), c, "b".repeat(before % 3));
+                assert_eq!(text_len(&text, b"<&"), before + 2, "{:?}", text);
+            }
+        }
+    }
+}
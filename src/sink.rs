@@ -0,0 +1,63 @@
+use crate::error::ParseError;
+use crate::position::Span;
+use crate::state::State;
+use crate::token::TokenType;
+
+// when token is emited it must be immediately handled by tree constructor.
+// The tokenizer hands every token to the sink as soon as it is emitted and applies the returned
+// instruction before consuming the next input character.
+pub trait TokenSink {
+    fn process_token(&mut self, token: TokenType, span: Span) -> SinkResult;
+
+    // called for every parse error, before the token that was being built when it occurred.
+    fn parse_error(&mut self, _error: ParseError, _span: Span) {}
+
+    // asked right before a markup declaration is tokenized, "<![CDATA[" only starts a CDATA
+    // section when the adjusted current node is an SVG or MathML element.
+    fn adjusted_current_node_present_but_not_in_html_namespace(&self) -> bool {
+        false
+    }
+}
+
+// the text states a tree builder can switch the tokenizer into after a start tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RawKind {
+    // <title>, <textarea>
+    RCData,
+    // <style>, <xmp>, <iframe>, <noembed>, <noframes>
+    RawText,
+    // <script>
+    ScriptData,
+}
+
+impl RawKind {
+    // the state an HTML element with this tag name switches the tokenizer to, if any. <noscript>
+    // is left out since it depends on whether scripting is enabled, and <plaintext> has its own
+    // SinkResult.
+    pub fn for_tag_name(tag_name: &str) -> Option<RawKind> {
+        match tag_name {
+            "title" | "textarea" => Some(RawKind::RCData),
+            "style" | "xmp" | "iframe" | "noembed" | "noframes" => Some(RawKind::RawText),
+            "script" => Some(RawKind::ScriptData),
+            _ => None,
+        }
+    }
+
+    pub fn state(self) -> State {
+        match self {
+            RawKind::RCData => State::RCData,
+            RawKind::RawText => State::RawText,
+            RawKind::ScriptData => State::ScriptData,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SinkResult {
+    Continue,
+    RawData(RawKind),
+    // <plaintext>, there is no way out of this state.
+    PlainText,
+    // stop tokenizing so that the script which just ended can run, see Tokenizer::run.
+    Script,
+}
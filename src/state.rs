@@ -1,4 +1,4 @@
-
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum State {
     Data,
     CharacterReference,
@@ -36,15 +36,13 @@ pub enum State {
     ScriptDataDoubleEscapedDashDash,
     ScriptDataDoubleEscapedLessThan,
     ScriptDataDoubleEscapeEnd,
-    BeforeAttributeNameState,
     AttributeName,
     AfterAttributeName,
-    BeforeAttributeName,
+    BeforeAttributeValue,
     AttributeValueDoubleQuoted,
     AttributeValueSingleQuoted,
     AttributeValueUnQuoted,
     AfterAttributeValueQuoted,
-    SelfClosingStartTag,
     CommentStart,
     CommentStartDash,
     Comment,
@@ -82,5 +80,9 @@ pub enum State {
     DecimalCharacterReferenceStart,
     DecimalCharacterReference,
     NumericCharacterReferenceEnd,
+    // not in the spec, only used with Tokenizer::set_processing_instructions.
+    ProcessingInstructionTarget,
+    ProcessingInstructionData,
+    ProcessingInstructionQuestionMark,
 }
 
@@ -0,0 +1,149 @@
+// A pair of markers a template language puts around its own syntax, like "{{" and "}}". With
+// delimiters set, the tokenizer leaves everything from an opening marker up to and including the
+// closing one alone: in text it becomes a TokenType::Template, and inside attribute values it is
+// kept as it was written, without decoding character references or ending the value early. An
+// opening marker without a closing one after it is read like it would be without delimiters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateDelimiter {
+    pub open: String,
+    pub close: String,
+}
+
+impl TemplateDelimiter {
+    pub fn new(open: &str, close: &str) -> Self {
+        TemplateDelimiter {
+            open: open.to_string(),
+            close: close.to_string(),
+        }
+    }
+
+    // Jinja, Handlebars and PHP. Delimiters are tried in order, which is why "{{{" comes before
+    // "{{".
+    pub fn common() -> Vec<TemplateDelimiter> {
+        vec![
+            TemplateDelimiter::new("{{{", "}}}"),
+            TemplateDelimiter::new("{{", "}}"),
+            TemplateDelimiter::new("{%", "%}"),
+            TemplateDelimiter::new("{#", "#}"),
+            TemplateDelimiter::new("<?", "?>"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Limit, LimitBehavior, LimitExceeded, Limits, LocalName, TemplateDelimiter, TokenType, Tokenizer};
+
+    fn tokenize(input: &str) -> Vec<TokenType> {
+        let mut tokenizer = Tokenizer::streaming();
+        tokenizer.set_template_delimiters(TemplateDelimiter::common());
+        tokenizer.feed(input);
+        tokenizer.end();
+        tokenizer.collect()
+    }
+
+    fn text(data: &str) -> TokenType {
+        TokenType::Character { data: data.to_string() }
+    }
+
+    fn template(data: &str) -> TokenType {
+        TokenType::Template { data: data.to_string() }
+    }
+
+    fn value_of(token: &TokenType, name: &str) -> String {
+        token.attribute(name).unwrap().to_string()
+    }
+
+    #[test]
+    fn templates_in_text() {
+        assert_eq!(
+            tokenize("a {{ b < c }} {{{ d & e }}}{% f %}{# g #}<?php h ?>i"),
+            [
+                text("a "),
+                template("{{ b < c }}"),
+                text(" "),
+                template("{{{ d & e }}}"),
+                template("{% f %}"),
+                template("{# g #}"),
+                template("<?php h ?>"),
+                text("i"),
+                TokenType::EOF
+            ]
+        );
+    }
+
+    #[test]
+    fn templates_in_attribute_values() {
+        let tokens = tokenize("<a href=\"{{ url & \"x\" }}&amp;\" title={{ a b }} alt='{% if '' %}'>");
+        assert_eq!(value_of(&tokens[0], "href"), "{{ url & \"x\" }}&");
+        assert_eq!(value_of(&tokens[0], "title"), "{{ a b }}");
+        assert_eq!(value_of(&tokens[0], "alt"), "{% if '' %}");
+    }
+
+    #[test]
+    fn unterminated_templates_are_ordinary_markup() {
+        let p = TokenType::StartTag {
+            tag_name: LocalName::from("p"),
+            self_closing: false,
+            attributes: Vec::new(),
+        };
+        assert_eq!(tokenize("a {{ b <p>"), [text("a {{ b "), p, TokenType::EOF]);
+        let tokens = tokenize("<a href=\"{{ b\">c");
+        assert_eq!(value_of(&tokens[0], "href"), "{{ b");
+        assert_eq!(tokens[1..], [text("c"), TokenType::EOF]);
+        // a later opener can still be closed, and "{{{" falls back to the "{{" inside it.
+        assert_eq!(tokenize("{% {{ a }}"), [text("{% "), template("{{ a }}"), TokenType::EOF]);
+        assert_eq!(tokenize("{{{ a }}"), [text("{"), template("{{ a }}"), TokenType::EOF]);
+    }
+
+    #[test]
+    fn templates_share_the_comment_length_limit() {
+        let tokenize = |behavior| {
+            let mut tokenizer = Tokenizer::streaming();
+            tokenizer.set_template_delimiters(TemplateDelimiter::common());
+            tokenizer.set_limits(Limits {
+                comment_length: Some(Limit::new(7, behavior)),
+                ..Limits::default()
+            });
+            tokenizer.feed("a{{ b }}{{ cd }}e");
+            tokenizer.end();
+            let tokens: Vec<_> = tokenizer.by_ref().collect();
+            (tokens, tokenizer.limit_exceeded())
+        };
+        assert_eq!(
+            tokenize(LimitBehavior::Truncate),
+            (vec![text("a"), template("{{ b }}"), template("{{ cd }"), text("e"), TokenType::EOF], None)
+        );
+        assert_eq!(
+            tokenize(LimitBehavior::Drop),
+            (vec![text("a"), template("{{ b }}"), text("e"), TokenType::EOF], None)
+        );
+        assert_eq!(
+            tokenize(LimitBehavior::Fail),
+            (vec![text("a"), template("{{ b }}")], Some(LimitExceeded::CommentLength))
+        );
+    }
+
+    #[test]
+    fn newlines_in_templates_are_normalized() {
+        assert_eq!(tokenize("{{ a\r\nb\rc }}"), [template("{{ a\nb\nc }}"), TokenType::EOF]);
+        let tokens = tokenize("<a href=\"{{ a\r\nb }}\">");
+        assert_eq!(value_of(&tokens[0], "href"), "{{ a\nb }}");
+    }
+
+    #[test]
+    fn templates_split_across_chunks() {
+        for input in ["a{{ b }}c<p title=\"{% d\r\n %}\">", "a{{ b {{ c }} d {{ e <p title=\"{% f\">{%}%}"] {
+            let mut tokenizer = Tokenizer::streaming();
+            tokenizer.set_template_delimiters(TemplateDelimiter::common());
+            let mut tokens = Vec::new();
+            for (index, c) in input.char_indices() {
+                tokenizer.feed(&input[index..index + c.len_utf8()]);
+                tokens.extend(tokenizer.by_ref());
+            }
+            tokenizer.end();
+            tokens.extend(tokenizer);
+            assert_eq!(tokens, tokenize(input));
+        }
+    }
+}
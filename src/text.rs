@@ -0,0 +1,95 @@
+use std::borrow::Cow;
+
+use crate::input::InputStream;
+
+// The text of a token while it is being built. As long as it is an unchanged run of the input it
+// is only kept as offsets into the input, and becomes an owned string the moment something that
+// is not the next piece of input gets appended: a lowercased letter, a decoded character
+// reference or a U+FFFD in place of a NULL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Text {
+    // absolute offsets, the same ones positions use.
+    Input { start: usize, end: usize },
+    Owned(String),
+}
+
+impl Text {
+    // empty text which expects its content to start at offset.
+    pub(crate) fn at(offset: usize) -> Self {
+        Text::Input { start: offset, end: offset }
+    }
+
+    // text appended to an empty run starts wherever at says it was read from, otherwise it has to
+    // follow the run directly.
+    pub(crate) fn push_str(&mut self, text: &str, at: usize, input: &InputStream) {
+        let (input, input_start) = input.buffer();
+        match self {
+            Text::Input { start, end } => {
+                if input_at(input, input_start, *end).starts_with(text) {
+                    *end += text.len();
+                } else if start == end && input_at(input, input_start, at).starts_with(text) {
+                    *start = at;
+                    *end = at + text.len();
+                } else {
+                    let mut owned = input_range(input, input_start, *start, *end).to_string();
+                    owned.push_str(text);
+                    *self = Text::Owned(owned);
+                }
+            }
+            Text::Owned(owned) => owned.push_str(text),
+        }
+    }
+
+    // appends the input from start to end.
+    pub(crate) fn push_input(&mut self, start: usize, end: usize, input: &InputStream) {
+        match self {
+            Text::Input { end: text_end, .. } if *text_end == start => *text_end = end,
+            _ => self.push_str(input.slice(start, end), start, input),
+        }
+    }
+
+    pub(crate) fn push(&mut self, c: char, at: usize, input: &InputStream) {
+        self.push_str(c.encode_utf8(&mut [0; 4]), at, input);
+    }
+
+    // in bytes.
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Text::Input { start, end } => end - start,
+            Text::Owned(owned) => owned.len(),
+        }
+    }
+
+    pub(crate) fn as_str<'a>(&'a self, input: &'a InputStream) -> &'a str {
+        match self {
+            Text::Input { start, end } => {
+                let (input, input_start) = input.buffer();
+                input_range(input, input_start, *start, *end)
+            }
+            Text::Owned(owned) => owned,
+        }
+    }
+
+    // input holds the input from input_start onwards.
+    pub(crate) fn into_cow(self, input: &str, input_start: usize) -> Cow<'_, str> {
+        match self {
+            Text::Input { start, end } => Cow::Borrowed(input_range(input, input_start, start, end)),
+            Text::Owned(owned) => Cow::Owned(owned),
+        }
+    }
+}
+
+// the input from offset onwards, empty when offset points at input which was already dropped.
+fn input_at(input: &str, input_start: usize, offset: usize) -> &str {
+    offset
+        .checked_sub(input_start)
+        .and_then(|offset| input.get(offset..))
+        .unwrap_or("")
+}
+
+fn input_range(input: &str, input_start: usize, start: usize, end: usize) -> &str {
+    if start == end {
+        return "";
+    }
+    &input[start - input_start..end - input_start]
+}
@@ -0,0 +1,423 @@
+use std::borrow::Cow;
+
+use crate::attribute::Attribute;
+use crate::input::InputStream;
+use crate::local_name::LocalName;
+use crate::text::Text;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DocTypeIdentifier {
+    Missing,
+    Available { id: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenType {
+    DocType {
+        // None when the name is missing, which is not the same as an empty name.
+        name: Option<String>,
+        public_identifier: DocTypeIdentifier,
+        system_identifier: DocTypeIdentifier,
+        force_quirks: bool,
+    },
+    StartTag {
+        tag_name: LocalName,
+        self_closing: bool,
+        attributes: Vec<Attribute>,
+    },
+    EndTag {
+        tag_name: LocalName,
+
+        // might remove the below two in the future if there is no use for them.
+        self_closing: bool,
+        attributes: Vec<Attribute>,
+        //-------------------------------------
+    },
+    Comment {
+        data: String,
+    },
+    // "<?target data?>", only with Tokenizer::set_processing_instructions.
+    ProcessingInstruction {
+        target: String,
+        data: String,
+    },
+    // a run of text, as many characters as there are in between the other tokens.
+    Character {
+        data: String,
+    },
+    // template syntax in between the markup, delimiters included, see TemplateDelimiter.
+    Template {
+        data: String,
+    },
+    EOF,
+}
+
+impl TokenType {
+    pub fn new_doctype(name: Option<String>) -> Self {
+        TokenType::DocType {
+            name,
+            public_identifier: DocTypeIdentifier::Missing,
+            system_identifier: DocTypeIdentifier::Missing,
+            force_quirks: false,
+        }
+    }
+
+    pub fn new_start_tag(tag_name: LocalName) -> Self {
+        TokenType::StartTag {
+            tag_name,
+            self_closing: false,
+            attributes: Vec::new(),
+        }
+    }
+
+    pub fn new_end_tag(tag_name: LocalName) -> Self {
+        TokenType::EndTag {
+            tag_name,
+            self_closing: false,
+            attributes: Vec::new(),
+        }
+    }
+
+    pub fn new_comment(data: String) -> Self {
+        TokenType::Comment { data }
+    }
+
+    pub fn attributes(&self) -> &[Attribute] {
+        match self {
+            TokenType::StartTag { attributes, .. } | TokenType::EndTag { attributes, .. } => attributes,
+            _ => &[],
+        }
+    }
+
+    fn attributes_mut(&mut self) -> &mut [Attribute] {
+        match self {
+            TokenType::StartTag { attributes, .. } | TokenType::EndTag { attributes, .. } => attributes,
+            _ => &mut [],
+        }
+    }
+
+    // The value of the attribute with the given name, written the way it appears in the markup
+    // ("href", "xlink:href"). Tags rarely have more than a handful of attributes so scanning them
+    // is faster than keeping a map around, and the tokenizer already made sure the names are
+    // unique.
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes()
+            .iter()
+            .find(|attribute| attribute.name.is(name))
+            .map(|attribute| attribute.value.as_str())
+    }
+
+    pub fn has_attribute(&self, name: &str) -> bool {
+        self.attribute(name).is_some()
+    }
+
+    // The tree builder calls these for start tags of SVG and MathML elements, in this order: the
+    // SVG or MathML adjustment first, then the foreign one.
+    pub fn adjust_svg_attributes(&mut self) {
+        self.attributes_mut().iter_mut().for_each(Attribute::adjust_svg);
+    }
+
+    pub fn adjust_mathml_attributes(&mut self) {
+        self.attributes_mut().iter_mut().for_each(Attribute::adjust_mathml);
+    }
+
+    pub fn adjust_foreign_attributes(&mut self) {
+        self.attributes_mut().iter_mut().for_each(Attribute::adjust_foreign);
+    }
+}
+
+// Same as TokenType, except that attribute values, comments and text borrow from the input
+// whenever they are an unchanged slice of it. Doctypes are rare enough to always be owned.
+// Attributes are name and value pairs here, straight from the tokenizer they are never in a
+// namespace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token<'a> {
+    DocType {
+        name: Option<String>,
+        public_identifier: DocTypeIdentifier,
+        system_identifier: DocTypeIdentifier,
+        force_quirks: bool,
+    },
+    StartTag {
+        tag_name: LocalName,
+        self_closing: bool,
+        attributes: Vec<(LocalName, Cow<'a, str>)>,
+    },
+    EndTag {
+        tag_name: LocalName,
+        self_closing: bool,
+        attributes: Vec<(LocalName, Cow<'a, str>)>,
+    },
+    Comment {
+        data: Cow<'a, str>,
+    },
+    ProcessingInstruction {
+        target: Cow<'a, str>,
+        data: Cow<'a, str>,
+    },
+    Character {
+        data: Cow<'a, str>,
+    },
+    Template {
+        data: Cow<'a, str>,
+    },
+    EOF,
+}
+
+impl Token<'_> {
+    pub fn into_owned(self) -> TokenType {
+        match self {
+            Token::DocType {
+                name,
+                public_identifier,
+                system_identifier,
+                force_quirks,
+            } => TokenType::DocType {
+                name,
+                public_identifier,
+                system_identifier,
+                force_quirks,
+            },
+            Token::StartTag {
+                tag_name,
+                self_closing,
+                attributes,
+            } => TokenType::StartTag {
+                tag_name,
+                self_closing,
+                attributes: into_owned_attributes(attributes),
+            },
+            Token::EndTag {
+                tag_name,
+                self_closing,
+                attributes,
+            } => TokenType::EndTag {
+                tag_name,
+                self_closing,
+                attributes: into_owned_attributes(attributes),
+            },
+            Token::Comment { data } => TokenType::Comment { data: data.into_owned() },
+            Token::ProcessingInstruction { target, data } => TokenType::ProcessingInstruction {
+                target: target.into_owned(),
+                data: data.into_owned(),
+            },
+            Token::Character { data } => TokenType::Character { data: data.into_owned() },
+            Token::Template { data } => TokenType::Template { data: data.into_owned() },
+            Token::EOF => TokenType::EOF,
+        }
+    }
+
+    // see TokenType::attribute.
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        match self {
+            Token::StartTag { attributes, .. } | Token::EndTag { attributes, .. } => attributes
+                .iter()
+                .find(|(attribute_name, _)| *attribute_name == name)
+                .map(|(_, value)| value.as_ref()),
+            _ => None,
+        }
+    }
+
+    pub fn has_attribute(&self, name: &str) -> bool {
+        self.attribute(name).is_some()
+    }
+
+    // the same token without anything borrowed, for text which can not be borrowed for as long as
+    // the token needs it.
+    pub(crate) fn into_static(self) -> Token<'static> {
+        let owned = |text: Cow<'_, str>| Cow::Owned(text.into_owned());
+        let owned_attributes = |attributes: Vec<(LocalName, Cow<'_, str>)>| {
+            attributes.into_iter().map(|(name, value)| (name, owned(value))).collect()
+        };
+        match self {
+            Token::DocType {
+                name,
+                public_identifier,
+                system_identifier,
+                force_quirks,
+            } => Token::DocType {
+                name,
+                public_identifier,
+                system_identifier,
+                force_quirks,
+            },
+            Token::StartTag {
+                tag_name,
+                self_closing,
+                attributes,
+            } => Token::StartTag {
+                tag_name,
+                self_closing,
+                attributes: owned_attributes(attributes),
+            },
+            Token::EndTag {
+                tag_name,
+                self_closing,
+                attributes,
+            } => Token::EndTag {
+                tag_name,
+                self_closing,
+                attributes: owned_attributes(attributes),
+            },
+            Token::Comment { data } => Token::Comment { data: owned(data) },
+            Token::ProcessingInstruction { target, data } => Token::ProcessingInstruction {
+                target: owned(target),
+                data: owned(data),
+            },
+            Token::Character { data } => Token::Character { data: owned(data) },
+            Token::Template { data } => Token::Template { data: owned(data) },
+            Token::EOF => Token::EOF,
+        }
+    }
+}
+
+fn into_owned_attributes(attributes: Vec<(LocalName, Cow<'_, str>)>) -> Vec<Attribute> {
+    attributes
+        .into_iter()
+        .map(|(name, value)| Attribute::new(name, value.into_owned()))
+        .collect()
+}
+
+// The token the tokenizer is building or has emitted but not handed out yet. Its text points into
+// the tokenizer's input until it gets turned into a Token.
+// EOF is spelled the way TokenType spells it.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum BufferedToken {
+    DocType {
+        name: Option<String>,
+        public_identifier: DocTypeIdentifier,
+        system_identifier: DocTypeIdentifier,
+        force_quirks: bool,
+    },
+    StartTag {
+        tag_name: Text,
+        self_closing: bool,
+        attributes: Vec<(Text, Text)>,
+    },
+    EndTag {
+        tag_name: Text,
+        self_closing: bool,
+        attributes: Vec<(Text, Text)>,
+    },
+    Comment {
+        data: Text,
+    },
+    ProcessingInstruction {
+        target: Text,
+        data: Text,
+    },
+    Character {
+        data: Text,
+    },
+    Template {
+        data: Text,
+    },
+    EOF,
+}
+
+impl BufferedToken {
+    pub(crate) fn new_doctype(name: Option<String>) -> Self {
+        BufferedToken::DocType {
+            name,
+            public_identifier: DocTypeIdentifier::Missing,
+            system_identifier: DocTypeIdentifier::Missing,
+            force_quirks: false,
+        }
+    }
+
+    // the tag name starts out empty at offset.
+    pub(crate) fn new_start_tag(offset: usize) -> Self {
+        BufferedToken::StartTag {
+            tag_name: Text::at(offset),
+            self_closing: false,
+            attributes: Vec::new(),
+        }
+    }
+
+    pub(crate) fn new_end_tag(offset: usize) -> Self {
+        BufferedToken::EndTag {
+            tag_name: Text::at(offset),
+            self_closing: false,
+            attributes: Vec::new(),
+        }
+    }
+
+    pub(crate) fn new_comment(offset: usize) -> Self {
+        BufferedToken::Comment { data: Text::at(offset) }
+    }
+
+    // the target starts out empty at offset, the data wherever it turns out to start.
+    pub(crate) fn new_processing_instruction(offset: usize) -> Self {
+        BufferedToken::ProcessingInstruction {
+            target: Text::at(offset),
+            data: Text::at(offset),
+        }
+    }
+
+    pub(crate) fn into_owned_token(self, input: &InputStream) -> TokenType {
+        let (input, input_start) = input.buffer();
+        self.into_token(input, input_start).into_owned()
+    }
+
+    // input holds the tokenizer's input from input_start onwards.
+    pub(crate) fn into_token(self, input: &str, input_start: usize) -> Token<'_> {
+        let attributes = |attributes: Vec<(Text, Text)>| {
+            attributes
+                .into_iter()
+                .map(|(name, value)| {
+                    (
+                        LocalName::new(&name.into_cow(input, input_start)),
+                        value.into_cow(input, input_start),
+                    )
+                })
+                .collect()
+        };
+
+        match self {
+            BufferedToken::DocType {
+                name,
+                public_identifier,
+                system_identifier,
+                force_quirks,
+            } => Token::DocType {
+                name,
+                public_identifier,
+                system_identifier,
+                force_quirks,
+            },
+            BufferedToken::StartTag {
+                tag_name,
+                self_closing,
+                attributes: tag_attributes,
+            } => Token::StartTag {
+                tag_name: LocalName::new(&tag_name.into_cow(input, input_start)),
+                self_closing,
+                attributes: attributes(tag_attributes),
+            },
+            BufferedToken::EndTag {
+                tag_name,
+                self_closing,
+                attributes: tag_attributes,
+            } => Token::EndTag {
+                tag_name: LocalName::new(&tag_name.into_cow(input, input_start)),
+                self_closing,
+                attributes: attributes(tag_attributes),
+            },
+            BufferedToken::Comment { data } => Token::Comment {
+                data: data.into_cow(input, input_start),
+            },
+            BufferedToken::ProcessingInstruction { target, data } => Token::ProcessingInstruction {
+                target: target.into_cow(input, input_start),
+                data: data.into_cow(input, input_start),
+            },
+            BufferedToken::Character { data } => Token::Character {
+                data: data.into_cow(input, input_start),
+            },
+            BufferedToken::Template { data } => Token::Template {
+                data: data.into_cow(input, input_start),
+            },
+            BufferedToken::EOF => Token::EOF,
+        }
+    }
+}
@@ -0,0 +1,3736 @@
+use std::collections::VecDeque;
+use std::ops::Deref;
+
+use memchr::memmem;
+
+use crate::decoder::Decoder;
+use crate::encoding::Encoding;
+use crate::entities;
+use crate::error::ParseError;
+use crate::input::{self, InputStream, is_control, is_noncharacter};
+use crate::limits::{self, Limit, LimitBehavior, LimitExceeded, Limits};
+use crate::options::TokenizerOptions;
+use crate::position::{Position, Span};
+use crate::scan;
+use crate::sink::{SinkResult, TokenSink};
+use crate::state::State;
+use crate::template::TemplateDelimiter;
+use crate::text::Text;
+use crate::token::{BufferedToken, DocTypeIdentifier, Token, TokenType};
+
+// the character reference state uses a return state to return to the state it was invoked from.
+
+// Most states consume one character but some might consume multiple at a time and the states are
+// given as follows:
+// 1) consume the character in the current state.
+// 2) consume the character and change the state to another one and then reconsume the character.
+// 3) switch to the next state to consume the next character.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenizerResult {
+    // the EOF token has been processed by the sink.
+    Done,
+    // the sink asked to pause, calling run again continues where it stopped.
+    Script,
+    // all of the input fed so far has been tokenized, feed more or call end.
+    NeedsInput,
+    // a limit set to LimitBehavior::Fail was exceeded, the tokenizer is done without an EOF token.
+    LimitExceeded(LimitExceeded),
+}
+
+// what LimitBehavior::Drop drops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LimitScope {
+    Attribute,
+    Token,
+}
+
+pub struct Tokenizer {
+    // the input fed so far, which also takes care of newline normalization.
+    input: InputStream,
+    // position of the next character to be consumed.
+    cursor: Position,
+    // position of the current input character.
+    char_start: Position,
+    // where the tag, comment or doctype that is being built started, which is its '<'.
+    token_start: Position,
+    // where the '&' of the character reference that is being consumed is.
+    char_ref_start: Position,
+    character_reference_code: u32,
+    current_state: State,
+    return_state: Option<State>,
+    temporary_buffer: String,
+
+    // the tag, comment or doctype token that is currently being built.
+    current_token: Option<BufferedToken>,
+    last_start_tag_name: Option<String>,
+    // the last attribute of the current tag gets removed once it is complete, because it repeats
+    // an earlier name or went over a limit.
+    drop_current_attribute: bool,
+    // the current token went over a limit and is not going to be emitted.
+    drop_current_token: bool,
+    // whether the adjusted current node of the tree builder is in the SVG or MathML namespace,
+    // CDATA sections are only allowed there.
+    in_foreign_content: bool,
+
+    // the last consumed character, None once the end of the input has been reached.
+    current_char: Option<char>,
+    reconsume: bool,
+
+    // tokens which have been emitted but not yet handed out.
+    pending: VecDeque<(BufferedToken, Span)>,
+    // Characters are collected into one character token per run of text, which gets emitted when
+    // any other token is.
+    text_run: Option<(Text, Span)>,
+    text_run_is_whitespace: bool,
+    split_leading_whitespace: bool,
+    eof_emitted: bool,
+
+    // parse errors which have not been handed out yet.
+    errors: Vec<(ParseError, Span)>,
+    limits: Limits,
+    // set once input_size has been reached, nothing fed afterwards gets read.
+    input_limit_reached: bool,
+    // start tags emitted or left out for tree_depth, minus end tags, see Limits::tree_depth.
+    depth: usize,
+    // set when a limit made the tokenizer stop.
+    limit_exceeded: Option<LimitExceeded>,
+    processing_instructions: bool,
+    template_delimiters: Vec<TemplateDelimiter>,
+    // the first byte of every opening delimiter, when there are any.
+    template_stop_bytes: Option<Box<[bool; 256]>>,
+    // For every delimiter, a stretch of the input which has no closing delimiter starting in it,
+    // so that waiting for more input or going past openers which are never closed does not look
+    // at the same input again.
+    template_searched: Vec<(usize, usize)>,
+    // turns the chunks given to feed_bytes into text, once there have been any.
+    decoder: Option<Decoder>,
+}
+
+impl Tokenizer {
+    // Tokenizes the whole input in one go.
+    pub fn new(input: &str) -> Self {
+        Tokenizer::with_options(input, TokenizerOptions::default())
+    }
+
+    // Same as new, with the input checked against limits too.
+    pub fn with_limits(input: &str, limits: Limits) -> Self {
+        Tokenizer::with_options(input, TokenizerOptions { limits, ..TokenizerOptions::default() })
+    }
+
+    pub fn with_options(input: &str, options: TokenizerOptions) -> Self {
+        let mut tokenizer = Tokenizer::streaming();
+        tokenizer.set_options(options);
+        tokenizer.feed(input);
+        tokenizer.end();
+        tokenizer
+    }
+
+    // A tokenizer without any input yet, the input is given in chunks using feed. It stops
+    // whenever it runs out of input in the middle of something, even inside a character reference
+    // or a keyword like DOCTYPE, and carries on once the next chunk arrives, so the tokens are the
+    // same as when tokenizing the whole input at once.
+    pub fn streaming() -> Self {
+        Tokenizer {
+            input: InputStream::new(),
+            cursor: Position::start(),
+            char_start: Position::start(),
+            token_start: Position::start(),
+            char_ref_start: Position::start(),
+            character_reference_code: 0,
+            current_state: State::Data,
+            return_state: None,
+            temporary_buffer: String::new(),
+            current_token: None,
+            last_start_tag_name: None,
+            drop_current_attribute: false,
+            drop_current_token: false,
+            in_foreign_content: false,
+            current_char: None,
+            reconsume: false,
+            pending: VecDeque::new(),
+            text_run: None,
+            text_run_is_whitespace: false,
+            split_leading_whitespace: false,
+            eof_emitted: false,
+            errors: Vec::new(),
+            limits: Limits::default(),
+            input_limit_reached: false,
+            depth: 0,
+            limit_exceeded: None,
+            processing_instructions: false,
+            template_delimiters: Vec::new(),
+            template_stop_bytes: None,
+            template_searched: Vec::new(),
+            decoder: None,
+        }
+    }
+
+    // Tokenizes bytes in the given encoding, usually the one Encoding::sniff found. A byte order
+    // mark still wins over it, see Decoder.
+    pub fn from_bytes(bytes: &[u8], encoding: Encoding) -> Self {
+        let mut tokenizer = Tokenizer::streaming();
+        tokenizer.set_encoding(encoding);
+        tokenizer.feed_bytes(bytes);
+        tokenizer.end();
+        tokenizer
+    }
+
+    // The encoding of the chunks given to feed_bytes, UTF-8 if this is not called. Once
+    // feed_bytes has been given any bytes it is too late, the text so far was decoded with the
+    // old one: the encoding is left alone and false is returned.
+    pub fn set_encoding(&mut self, encoding: Encoding) -> bool {
+        if self.decoder.as_ref().is_some_and(Decoder::has_started) {
+            return false;
+        }
+        self.decoder = Some(encoding.new_decoder());
+        true
+    }
+
+    // the encoding feed_bytes decodes from, None if there is no decoder yet.
+    pub fn encoding(&self) -> Option<Encoding> {
+        self.decoder.as_ref().map(Decoder::encoding)
+    }
+
+    // Same as feed, for input that has not been decoded yet. A character split in between two
+    // chunks is fine. Mixing this with feed only works in between characters.
+    pub fn feed_bytes(&mut self, chunk: &[u8]) {
+        let decoder = self.decoder.get_or_insert_with(|| Encoding::Utf8.new_decoder());
+        let mut text = String::new();
+        decoder.decode(chunk, &mut text);
+        self.feed(&text);
+    }
+
+    pub fn feed(&mut self, chunk: &str) {
+        // Only the current input character may be looked at again, but the text of the tokens
+        // which are being built or still pending points into the input too, and so does the
+        // text of a "<" or "&" which might still turn out to be plain text. Anything before all
+        // of that can go.
+        let mut keep_from = self.char_start.offset;
+        if self.current_token.is_some() || !self.in_text_state() {
+            keep_from = keep_from
+                .min(self.token_start.offset)
+                .min(self.char_ref_start.offset);
+        }
+        if let Some((_, span)) = self.pending.front() {
+            keep_from = keep_from.min(span.start.offset);
+        } else if let Some((_, span)) = &self.text_run {
+            keep_from = keep_from.min(span.start.offset);
+        }
+
+        // token_start and char_ref_start are not reset after use, so they may point at input
+        // which is already gone, which discard_before does not mind.
+        self.input.discard_before(keep_from);
+        let chunk = self.limit_input(chunk);
+        self.input.push_str(chunk);
+    }
+
+    // the part of chunk that is within input_size.
+    fn limit_input<'c>(&mut self, chunk: &'c str) -> &'c str {
+        if self.input_limit_reached {
+            return "";
+        }
+        let Some(limit) = self.limits.input_size else {
+            return chunk;
+        };
+        let (buffer, start) = self.input.buffer();
+        let fed = start + buffer.len();
+        if fed + chunk.len() <= limit.max {
+            return chunk;
+        }
+
+        self.input_limit_reached = true;
+        match limit.behavior {
+            LimitBehavior::Truncate => limits::truncate(chunk, limit.max.saturating_sub(fed)),
+            LimitBehavior::Drop => "",
+            LimitBehavior::Fail => {
+                self.fail(LimitExceeded::InputSize);
+                ""
+            }
+        }
+    }
+
+    // the states which only emit the current input character, outside of them a tag, comment
+    // or character reference might be halfway through.
+    fn in_text_state(&self) -> bool {
+        matches!(
+            self.current_state,
+            State::Data
+                | State::RCData
+                | State::RawText
+                | State::ScriptData
+                | State::ScriptDataEscaped
+                | State::ScriptDataDoubleEscaped
+                | State::PlainText
+                | State::CDataSection
+        )
+    }
+
+    // there is no more input, whatever is left can be tokenized up to the EOF token.
+    pub fn end(&mut self) {
+        // a character left unfinished by the last chunk is a U+FFFD.
+        if let Some(decoder) = &mut self.decoder {
+            let mut text = String::new();
+            decoder.finish(&mut text);
+            if !text.is_empty() {
+                self.feed(&text);
+            }
+        }
+        self.input.end();
+    }
+
+    // The insertion point is where document.write puts its text. Before running a script the
+    // tree builder pushes one just before the next input character, which is right after the
+    // </script> when run has returned TokenizerResult::Script, and pops it once the script is
+    // done, which brings back the insertion point of the script it was nested in, if any. The
+    // tokenizer stops at the insertion point as if it had run out of input until it is popped.
+    pub fn push_insertion_point(&mut self) {
+        self.input.push_insertion_point(self.cursor.offset);
+    }
+
+    pub fn pop_insertion_point(&mut self) {
+        self.input.pop_insertion_point();
+    }
+
+    // without one, document.write has to start over with a new document instead.
+    pub fn has_insertion_point(&self) -> bool {
+        self.input.has_insertion_point()
+    }
+
+    // document.write: text goes in just before the insertion point, so it is read ahead of the
+    // rest of the input and several writes come out in the order they were made. Running the
+    // tokenizer again reads it up to the insertion point, or up to a </script> in it which starts
+    // a nested script. Offsets and positions after it count the inserted text as part of the
+    // input. Does nothing without an insertion point.
+    pub fn insert(&mut self, text: &str) {
+        if !self.input.has_insertion_point() {
+            return;
+        }
+        let text = self.limit_input(text);
+        self.input.insert(text);
+        // the input after the insertion point moves.
+        self.template_searched.fill((0, 0));
+    }
+
+    // Pushes every token to the sink the moment it is emitted. Unlike the iterator interface this
+    // lets the sink switch the tokenizer state in between tokens.
+    pub fn run<S: TokenSink>(&mut self, sink: &mut S) -> TokenizerResult {
+        loop {
+            for (error, span) in self.errors.drain(..) {
+                sink.parse_error(error, span);
+            }
+
+            while let Some((token, span)) = self.pending.pop_front() {
+                let token = token.into_owned_token(&self.input);
+                match sink.process_token(token, span) {
+                    SinkResult::Continue => {}
+                    SinkResult::RawData(kind) => {
+                        self.current_state = kind.state();
+                    }
+                    SinkResult::PlainText => {
+                        self.current_state = State::PlainText;
+                    }
+                    SinkResult::Script => {
+                        return TokenizerResult::Script;
+                    }
+                }
+            }
+
+            if self.eof_emitted {
+                return TokenizerResult::Done;
+            }
+            if let Some(exceeded) = self.limit_exceeded {
+                return TokenizerResult::LimitExceeded(exceeded);
+            }
+            if self.current_state == State::MarkupDeclarationOpen {
+                self.in_foreign_content = sink.adjusted_current_node_present_but_not_in_html_namespace();
+            }
+            if !self.step() {
+                return TokenizerResult::NeedsInput;
+            }
+        }
+    }
+
+    // The tree builder decides which state to continue in after a start tag, e.g. RCDATA after
+    // <title> or script data after <script>. Prefer SinkResult when using run.
+    pub fn set_state(&mut self, state: State) {
+        self.current_state = state;
+    }
+
+    pub fn state(&self) -> State {
+        self.current_state
+    }
+
+    // the name end tags in RCDATA, RAWTEXT and script data are compared against.
+    pub fn last_start_tag_name(&self) -> Option<&str> {
+        self.last_start_tag_name.as_deref()
+    }
+
+    // needed when starting in one of the text states without having seen the start tag, like
+    // when parsing the contents of a <textarea> as a fragment.
+    pub fn set_last_start_tag_name(&mut self, name: Option<&str>) {
+        self.last_start_tag_name = name.map(|name| name.to_string());
+    }
+
+    // When using the iterator interface the consumer has to keep this up to date for "<![CDATA["
+    // to be tokenized as a CDATA section instead of a bogus comment.
+    pub fn set_in_foreign_content(&mut self, in_foreign_content: bool) {
+        self.in_foreign_content = in_foreign_content;
+    }
+
+    // Same as calling each of the setters, see TokenizerOptions.
+    pub fn set_options(&mut self, options: TokenizerOptions) {
+        self.set_split_leading_whitespace(options.split_leading_whitespace);
+        self.set_processing_instructions(options.processing_instructions);
+        self.set_template_delimiters(options.template_delimiters);
+        self.set_limits(options.limits);
+    }
+
+    // Whitespace at the start of a run of text comes out as a character token of its own, which is
+    // what the tree builder wants in the modes that treat whitespace differently from other text.
+    pub fn set_split_leading_whitespace(&mut self, split_leading_whitespace: bool) {
+        self.split_leading_whitespace = split_leading_whitespace;
+    }
+
+    // Only input fed after this is checked against input_size.
+    pub fn set_limits(&mut self, limits: Limits) {
+        self.limits = limits;
+    }
+
+    pub fn limits(&self) -> Limits {
+        self.limits
+    }
+
+    // why the tokenizer stopped early, the iterator interface simply runs out of tokens.
+    pub fn limit_exceeded(&self) -> Option<LimitExceeded> {
+        self.limit_exceeded
+    }
+
+    // "<?" starts a TokenType::ProcessingInstruction which ends at "?>", instead of a bogus
+    // comment which ends at the first ">". The unexpected-question-mark-instead-of-tag-name error
+    // is still reported, it is not HTML either way. "<?>" is an empty instruction, and without a
+    // "?>" before the end of the input it is a bogus comment after all, so turning this on never
+    // swallows the rest of the page. Template delimiters starting with "<?" take precedence.
+    pub fn set_processing_instructions(&mut self, processing_instructions: bool) {
+        self.processing_instructions = processing_instructions;
+    }
+
+    // Template syntax is left alone in text and attribute values, see TemplateDelimiter. An empty
+    // opening delimiter would match everywhere and is ignored.
+    pub fn set_template_delimiters(&mut self, delimiters: Vec<TemplateDelimiter>) {
+        self.template_delimiters = delimiters.into_iter().filter(|delimiter| !delimiter.open.is_empty()).collect();
+        self.template_searched = vec![(0, 0); self.template_delimiters.len()];
+        self.template_stop_bytes = if self.template_delimiters.is_empty() {
+            None
+        } else {
+            let mut stop_bytes = Box::new([false; 256]);
+            for delimiter in &self.template_delimiters {
+                stop_bytes[delimiter.open.as_bytes()[0] as usize] = true;
+            }
+            Some(stop_bytes)
+        };
+    }
+
+    // the parse errors seen so far when using the iterator interface, in the order they occurred.
+    pub fn errors(&self) -> &[(ParseError, Span)] {
+        &self.errors
+    }
+
+    pub fn take_errors(&mut self) -> Vec<(ParseError, Span)> {
+        std::mem::take(&mut self.errors)
+    }
+
+    // Same as Iterator::next but also returns where the token is in the input. Both return None
+    // when a streaming tokenizer needs more input, and continue after the next feed.
+    pub fn next_spanned(&mut self) -> Option<(TokenType, Span)> {
+        let (token, span) = self.next_buffered()?;
+        Some((token.into_owned_token(&self.input), span))
+    }
+
+    fn next_buffered(&mut self) -> Option<(BufferedToken, Span)> {
+        while self.pending.is_empty() {
+            if self.eof_emitted || !self.step() {
+                return None;
+            }
+        }
+        self.pending.pop_front()
+    }
+
+    // errors are reported at the current input character.
+    fn emit_error(&mut self, error: ParseError) {
+        self.emit_error_at(error, Span::new(self.char_start, self.cursor));
+    }
+
+    fn emit_error_at(&mut self, error: ParseError, span: Span) {
+        self.errors.push((error, span));
+    }
+
+    fn consume(&mut self) -> Option<char> {
+        if self.reconsume {
+            self.reconsume = false;
+            return self.current_char;
+        }
+
+        self.char_start = self.cursor;
+        let c = match self.input.next_char(self.cursor) {
+            Some((c, next)) => {
+                self.cursor = next;
+                if let Some(error) = input::preprocessing_error(c) {
+                    self.emit_error(error);
+                }
+                Some(c)
+            }
+            None => None,
+        };
+        self.current_char = c;
+        c
+    }
+
+    // the input from the next character to be consumed onwards, as it was fed.
+    fn remaining_input(&self) -> &str {
+        self.input.from(self.cursor.offset)
+    }
+
+    // whether the remaining input is too short to tell if it starts with one of the keywords.
+    fn might_become(&self, keywords: &[&str]) -> bool {
+        if self.input.is_ended() {
+            return false;
+        }
+
+        let rest = self.remaining_input().as_bytes();
+        keywords.iter().any(|keyword| {
+            rest.len() < keyword.len() && rest.eq_ignore_ascii_case(&keyword.as_bytes()[..rest.len()])
+        })
+    }
+
+    fn reconsume_in(&mut self, state: State) {
+        self.reconsume = true;
+        self.current_state = state;
+    }
+
+    // checks the upcoming input without consuming any of it.
+    fn next_chars_are(&self, expected: &str, case_insensitive: bool) -> bool {
+        let rest = self.remaining_input().as_bytes();
+        if rest.len() < expected.len() {
+            return false;
+        }
+
+        let candidate = &rest[..expected.len()];
+        if case_insensitive {
+            candidate.eq_ignore_ascii_case(expected.as_bytes())
+        } else {
+            candidate == expected.as_bytes()
+        }
+    }
+
+    // consumes characters that were already matched using next_chars_are.
+    fn consume_matched(&mut self, matched: &str) {
+        let start = self.cursor.offset;
+        for c in self.input.slice(start, start + matched.len()).chars() {
+            self.char_start = self.cursor;
+            self.cursor = self.cursor.advance(c);
+        }
+    }
+
+    // the end of the input consumed so far, a character that is going to be reconsumed does not
+    // count.
+    fn current_end(&self) -> Position {
+        if self.reconsume {
+            self.char_start
+        } else {
+            self.cursor
+        }
+    }
+
+    fn emit(&mut self, token: BufferedToken, span: Span) {
+        self.emit_text_run();
+        if self.too_deep() && token != BufferedToken::EOF {
+            return;
+        }
+        match &token {
+            BufferedToken::EndTag { self_closing, attributes, .. } => {
+                if !attributes.is_empty() {
+                    self.emit_error_at(ParseError::EndTagWithAttributes, span);
+                }
+                if *self_closing {
+                    self.emit_error_at(ParseError::EndTagWithTrailingSolidus, span);
+                }
+            }
+            BufferedToken::StartTag { tag_name, .. } => {
+                // reuses the allocation, there is one of these for every start tag.
+                let last_start_tag_name = self.last_start_tag_name.get_or_insert_with(String::new);
+                last_start_tag_name.clear();
+                last_start_tag_name.push_str(tag_name.as_str(&self.input));
+            }
+            BufferedToken::EOF => {
+                self.eof_emitted = true;
+            }
+            _ => {}
+        }
+        self.pending.push_back((token, span));
+    }
+
+    // emits c as a character token located at the current input character.
+    fn emit_char(&mut self, c: char) {
+        self.emit_char_at(c, Span::new(self.char_start, self.cursor));
+    }
+
+    // c gets added to the current run of text, which keeps borrowing the input at span if that is
+    // where c came from.
+    fn emit_char_at(&mut self, c: char, span: Span) {
+        let is_whitespace = c.is_ascii_whitespace();
+        if self.split_leading_whitespace && self.text_run_is_whitespace && !is_whitespace {
+            self.emit_text_run();
+        }
+
+        match &mut self.text_run {
+            Some((data, run_span)) => {
+                data.push(c, span.start.offset, &self.input);
+                run_span.end = span.end;
+                self.text_run_is_whitespace &= is_whitespace;
+            }
+            None => {
+                let mut data = Text::at(span.start.offset);
+                data.push(c, span.start.offset, &self.input);
+                self.text_run = Some((data, span));
+                self.text_run_is_whitespace = is_whitespace;
+            }
+        }
+    }
+
+    // Emits the text from the cursor up to the next of the special bytes, see scan::text_len, all
+    // at once instead of going through the state one character at a time. Returns false when the
+    // cursor is at such a byte already, which is left to the state.
+    fn emit_text_up_to(&mut self, special: &[u8]) -> bool {
+        let mut rest = self.remaining_input();
+        // text in the data state also stops where a template might start. That is looked for
+        // first, so the text after a template is not scanned over and over.
+        if let Some(template_stop_bytes) = &self.template_stop_bytes
+            && self.current_state == State::Data
+        {
+            let len = rest.bytes().position(|b| template_stop_bytes[b as usize]).unwrap_or(rest.len());
+            rest = &rest[..len];
+        }
+        let mut len = scan::text_len(rest, special);
+        let rest = rest.as_bytes();
+        if len == 0 {
+            return false;
+        }
+
+        // leading whitespace has to end up in a token of its own.
+        if self.split_leading_whitespace && (self.text_run.is_none() || self.text_run_is_whitespace) {
+            let whitespace = rest[..len].iter().take_while(|b| b.is_ascii_whitespace()).count();
+            if whitespace > 0 {
+                len = whitespace;
+            }
+        }
+
+        let start = self.cursor;
+        let end = start.advance_str(self.input.slice(start.offset, start.offset + len));
+        self.char_start = end;
+        self.cursor = end;
+        self.emit_input_text(Span::new(start, end));
+        true
+    }
+
+    // like emit_char_at, for text which is exactly what the input has at span.
+    fn emit_input_text(&mut self, span: Span) {
+        // the whitespace only matters when splitting it off, no need to look for it otherwise.
+        let (starts_with_whitespace, is_whitespace) = if self.split_leading_whitespace {
+            let text = self.input.slice(span.start.offset, span.end.offset).as_bytes();
+            (
+                text.first().is_some_and(|b| b.is_ascii_whitespace()),
+                text.iter().all(|b| b.is_ascii_whitespace()),
+            )
+        } else {
+            (false, false)
+        };
+        if self.split_leading_whitespace && self.text_run_is_whitespace && !starts_with_whitespace {
+            self.emit_text_run();
+        }
+
+        match &mut self.text_run {
+            Some((data, run_span)) => {
+                data.push_input(span.start.offset, span.end.offset, &self.input);
+                run_span.end = span.end;
+                self.text_run_is_whitespace &= is_whitespace;
+            }
+            None => {
+                let data = Text::Input {
+                    start: span.start.offset,
+                    end: span.end.offset,
+                };
+                self.text_run = Some((data, span));
+                self.text_run_is_whitespace = is_whitespace;
+            }
+        }
+    }
+
+    fn emit_text_run(&mut self) {
+        if let Some((data, span)) = self.text_run.take()
+            && !self.too_deep()
+        {
+            self.pending.push_back((BufferedToken::Character { data }, span));
+        }
+    }
+
+    // emits every character of chars, which was read from the input starting at start.
+    fn emit_chars_from(&mut self, chars: &str, start: Position) {
+        let mut position = start;
+        for c in chars.chars() {
+            let end = position.advance(c);
+            self.emit_char_at(c, Span::new(position, end));
+            position = end;
+        }
+    }
+
+    // starts building token, whatever the previous one was dropped for does not carry over.
+    fn start_token(&mut self, token: BufferedToken) {
+        self.current_token = Some(token);
+        self.drop_current_token = false;
+        self.drop_current_attribute = false;
+    }
+
+    fn emit_current_token(&mut self) {
+        self.drop_last_attribute();
+        if std::mem::take(&mut self.drop_current_token) {
+            self.current_token = None;
+        }
+        if let Some(token) = self.current_token.take() {
+            let span = Span::new(self.token_start, self.current_end());
+            // the text before a tag is inside whatever the tags before it left open.
+            self.emit_text_run();
+            if self.fits_tree_depth(&token) {
+                self.emit(token, span);
+            }
+        }
+    }
+
+    // Counts how deep token leaves the tags nested and whether it is to be emitted, see
+    // Limits::tree_depth. The end tag of a start tag that was left out is left out too.
+    fn fits_tree_depth(&mut self, token: &BufferedToken) -> bool {
+        let opens = match token {
+            BufferedToken::StartTag { tag_name, self_closing, .. } => {
+                // only foreign elements can be closed by their start tag.
+                let closed = *self_closing && self.in_foreign_content;
+                !closed && !VOID_ELEMENTS.contains(&tag_name.as_str(&self.input))
+            }
+            BufferedToken::EndTag { .. } => {
+                let fits = self.limits.tree_depth.is_none_or(|limit| self.depth <= limit.max);
+                self.depth = self.depth.saturating_sub(1);
+                return fits;
+            }
+            _ => return true,
+        };
+        let Some(limit) = self.limits.tree_depth else {
+            return true;
+        };
+        if self.depth < limit.max {
+            self.depth += usize::from(opens);
+            return true;
+        }
+        if limit.behavior == LimitBehavior::Fail {
+            self.fail(LimitExceeded::TreeDepth);
+            return false;
+        }
+        self.depth += usize::from(opens);
+        false
+    }
+
+    // whether the tokens being emitted are inside a tag which LimitBehavior::Drop left out for
+    // tree_depth, and are to be left out with it.
+    fn too_deep(&self) -> bool {
+        self.limits
+            .tree_depth
+            .is_some_and(|limit| limit.behavior == LimitBehavior::Drop && self.depth > limit.max)
+    }
+
+    fn emit_eof(&mut self) {
+        self.emit(BufferedToken::EOF, Span::new(self.cursor, self.cursor));
+    }
+
+    // the text helpers below append at the current input character, which keeps the text
+    // borrowing from the input if c is that character.
+    fn append_to_tag_name(&mut self, c: char) {
+        let len = match &self.current_token {
+            Some(BufferedToken::StartTag { tag_name, .. })
+            | Some(BufferedToken::EndTag { tag_name, .. }) if !self.drop_current_token => tag_name.len(),
+            _ => return,
+        };
+        let mut buffer = [0; 4];
+        let c = self.fit(
+            c.encode_utf8(&mut buffer),
+            len,
+            self.limits.name_length,
+            LimitExceeded::NameLength,
+            LimitScope::Token,
+        );
+        match &mut self.current_token {
+            Some(BufferedToken::StartTag { tag_name, .. })
+            | Some(BufferedToken::EndTag { tag_name, .. }) => {
+                tag_name.push_str(c, self.char_start.offset, &self.input);
+            }
+            _ => {}
+        }
+    }
+
+    fn set_self_closing(&mut self) {
+        match &mut self.current_token {
+            Some(BufferedToken::StartTag { self_closing, .. })
+            | Some(BufferedToken::EndTag { self_closing, .. }) => {
+                *self_closing = true;
+            }
+            _ => {}
+        }
+    }
+
+    // Start a new attribute in the current tag token, name is either empty or the current input
+    // character.
+    fn start_new_attribute(&mut self, name: &str) {
+        self.drop_last_attribute();
+        let count = match &self.current_token {
+            Some(BufferedToken::StartTag { attributes, .. })
+            | Some(BufferedToken::EndTag { attributes, .. }) => attributes.len(),
+            _ => return,
+        };
+        if let Some(limit) = self.limits.attributes_per_tag
+            && count >= limit.max
+        {
+            // Truncate still adds the attribute, to have something to append its name and value
+            // to, and drops it once those are done.
+            self.drop_current_attribute = true;
+            match limit.behavior {
+                LimitBehavior::Truncate => {}
+                LimitBehavior::Drop => self.drop_current_token = true,
+                LimitBehavior::Fail => self.fail(LimitExceeded::AttributesPerTag),
+            }
+        }
+        let name = if self.drop_current_attribute || self.drop_current_token {
+            ""
+        } else {
+            self.fit(name, 0, self.limits.name_length, LimitExceeded::NameLength, LimitScope::Attribute)
+        };
+
+        match &mut self.current_token {
+            Some(BufferedToken::StartTag { attributes, .. })
+            | Some(BufferedToken::EndTag { attributes, .. }) => {
+                let offset = self.char_start.offset;
+                let mut attribute_name = Text::at(offset);
+                attribute_name.push_str(name, offset, &self.input);
+                attributes.push((attribute_name, Text::at(self.cursor.offset)));
+            }
+            _ => {}
+        }
+    }
+
+    // When leaving the attribute name state, the name is compared to the other attributes on the
+    // same token. A repeated name is an error and the new attribute gets removed from the token,
+    // which waits until its value has been consumed.
+    fn check_duplicate_attribute(&mut self) {
+        // an attribute that is dropped anyway might not have its whole name.
+        if self.drop_current_attribute || self.drop_current_token {
+            return;
+        }
+        let is_duplicate = match &self.current_token {
+            Some(BufferedToken::StartTag { attributes, .. })
+            | Some(BufferedToken::EndTag { attributes, .. }) => match attributes.split_last() {
+                Some(((name, _), others)) => {
+                    let name = name.as_str(&self.input);
+                    others.iter().any(|(other, _)| other.as_str(&self.input) == name)
+                }
+                None => false,
+            },
+            _ => false,
+        };
+
+        if is_duplicate {
+            self.emit_error(ParseError::DuplicateAttribute);
+            self.drop_current_attribute = true;
+        }
+    }
+
+    fn drop_last_attribute(&mut self) {
+        if !self.drop_current_attribute {
+            return;
+        }
+        self.drop_current_attribute = false;
+
+        match &mut self.current_token {
+            Some(BufferedToken::StartTag { attributes, .. })
+            | Some(BufferedToken::EndTag { attributes, .. }) => {
+                attributes.pop();
+            }
+            _ => {}
+        }
+    }
+
+    // the name and value of the attribute that is being built, unless it is going to be dropped.
+    fn current_attribute(&self) -> Option<&(Text, Text)> {
+        if self.drop_current_attribute || self.drop_current_token {
+            return None;
+        }
+        match &self.current_token {
+            Some(BufferedToken::StartTag { attributes, .. })
+            | Some(BufferedToken::EndTag { attributes, .. }) => attributes.last(),
+            _ => None,
+        }
+    }
+
+    fn append_to_attribute_name(&mut self, c: char) {
+        let Some((name, _)) = self.current_attribute() else {
+            return;
+        };
+        let len = name.len();
+        let mut buffer = [0; 4];
+        let c = self.fit(
+            c.encode_utf8(&mut buffer),
+            len,
+            self.limits.name_length,
+            LimitExceeded::NameLength,
+            LimitScope::Attribute,
+        );
+        match &mut self.current_token {
+            Some(BufferedToken::StartTag { attributes, .. })
+            | Some(BufferedToken::EndTag { attributes, .. }) => {
+                if let Some((name, _)) = attributes.last_mut() {
+                    name.push_str(c, self.char_start.offset, &self.input);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn append_to_attribute_value(&mut self, c: char) {
+        self.append_str_to_attribute_value(c.encode_utf8(&mut [0; 4]), self.char_start.offset);
+    }
+
+    // value was read from the input at offset, or is what the input there turned into.
+    fn append_str_to_attribute_value(&mut self, value_to_append: &str, offset: usize) {
+        let Some((_, value)) = self.current_attribute() else {
+            return;
+        };
+        let len = value.len();
+        let value_to_append = self.fit(
+            value_to_append,
+            len,
+            self.limits.attribute_value_length,
+            LimitExceeded::AttributeValueLength,
+            LimitScope::Attribute,
+        );
+        match &mut self.current_token {
+            Some(BufferedToken::StartTag { attributes, .. })
+            | Some(BufferedToken::EndTag { attributes, .. }) => {
+                if let Some((_, value)) = attributes.last_mut() {
+                    value.push_str(value_to_append, offset, &self.input);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn append_to_comment(&mut self, data_to_append: &str) {
+        let len = match &self.current_token {
+            Some(BufferedToken::Comment { data }) if !self.drop_current_token => data.len(),
+            _ => return,
+        };
+        let data_to_append =
+            self.fit(data_to_append, len, self.limits.comment_length, LimitExceeded::CommentLength, LimitScope::Token);
+        if let Some(BufferedToken::Comment { data }) = &mut self.current_token {
+            data.push_str(data_to_append, self.char_start.offset, &self.input);
+        }
+    }
+
+    // Processing instructions stand in for the bogus comments they would be otherwise, so they
+    // share the comment length limit.
+    fn append_to_processing_instruction(&mut self, to_target: bool, c: char) {
+        self.append_to_processing_instruction_at(to_target, c, self.char_start.offset);
+    }
+
+    fn append_to_processing_instruction_at(&mut self, to_target: bool, c: char, offset: usize) {
+        let len = match &self.current_token {
+            Some(BufferedToken::ProcessingInstruction { target, data }) if !self.drop_current_token => {
+                if to_target { target.len() } else { data.len() }
+            }
+            _ => return,
+        };
+        let mut buffer = [0; 4];
+        let c = self.fit(
+            c.encode_utf8(&mut buffer),
+            len,
+            self.limits.comment_length,
+            LimitExceeded::CommentLength,
+            LimitScope::Token,
+        );
+        if let Some(BufferedToken::ProcessingInstruction { target, data }) = &mut self.current_token {
+            let text = if to_target { target } else { data };
+            text.push_str(c, offset, &self.input);
+        }
+    }
+
+    fn processing_instruction_target_is_empty(&self) -> bool {
+        match &self.current_token {
+            Some(BufferedToken::ProcessingInstruction { target, .. }) => target.len() == 0,
+            _ => false,
+        }
+    }
+
+    fn processing_instruction_data_is_empty(&self) -> bool {
+        match &self.current_token {
+            Some(BufferedToken::ProcessingInstruction { data, .. }) => data.len() == 0,
+            _ => false,
+        }
+    }
+
+    // The input ended without a "?>", so the instruction was a bogus comment after all and is
+    // read again as one, from the "?" up to the first ">". The errors found inside it are reported
+    // again by the second read.
+    fn reread_processing_instruction_as_bogus_comment(&mut self) {
+        let question_mark = self.token_start.advance('<');
+        self.errors.retain(|(_, span)| span.start.offset <= question_mark.offset);
+        self.char_start = question_mark;
+        self.cursor = question_mark.advance('?');
+        self.current_char = Some('?');
+        self.start_token(BufferedToken::new_comment(question_mark.offset));
+        self.reconsume_in(State::BogusComment);
+    }
+
+    fn append_to_doctype_name(&mut self, c: char) {
+        let len = match &self.current_token {
+            Some(BufferedToken::DocType { name: Some(name), .. }) => name.len(),
+            _ => return,
+        };
+        let mut buffer = [0; 4];
+        let c = self.fit_doctype(c.encode_utf8(&mut buffer), len);
+        if let Some(BufferedToken::DocType { name: Some(name), .. }) = &mut self.current_token {
+            name.push_str(c);
+        }
+    }
+
+    fn set_force_quirks(&mut self) {
+        if let Some(BufferedToken::DocType { force_quirks, .. }) = &mut self.current_token {
+            *force_quirks = true;
+        }
+    }
+
+    fn set_public_identifier_empty(&mut self) {
+        if let Some(BufferedToken::DocType { public_identifier, .. }) = &mut self.current_token {
+            *public_identifier = DocTypeIdentifier::Available { id: String::new() };
+        }
+    }
+
+    fn append_to_public_identifier(&mut self, c: char) {
+        let len = match &self.current_token {
+            Some(BufferedToken::DocType {
+                public_identifier: DocTypeIdentifier::Available { id },
+                ..
+            }) => id.len(),
+            _ => return,
+        };
+        let mut buffer = [0; 4];
+        let c = self.fit_doctype(c.encode_utf8(&mut buffer), len);
+        if let Some(BufferedToken::DocType {
+            public_identifier: DocTypeIdentifier::Available { id },
+            ..
+        }) = &mut self.current_token
+        {
+            id.push_str(c);
+        }
+    }
+
+    fn set_system_identifier_empty(&mut self) {
+        if let Some(BufferedToken::DocType { system_identifier, .. }) = &mut self.current_token {
+            *system_identifier = DocTypeIdentifier::Available { id: String::new() };
+        }
+    }
+
+    fn append_to_system_identifier(&mut self, c: char) {
+        let len = match &self.current_token {
+            Some(BufferedToken::DocType {
+                system_identifier: DocTypeIdentifier::Available { id },
+                ..
+            }) => id.len(),
+            _ => return,
+        };
+        let mut buffer = [0; 4];
+        let c = self.fit_doctype(c.encode_utf8(&mut buffer), len);
+        if let Some(BufferedToken::DocType {
+            system_identifier: DocTypeIdentifier::Available { id },
+            ..
+        }) = &mut self.current_token
+        {
+            id.push_str(c);
+        }
+    }
+
+    fn fit_doctype<'t>(&mut self, text: &'t str, len: usize) -> &'t str {
+        if self.drop_current_token {
+            return "";
+        }
+        self.fit(text, len, self.limits.doctype_length, LimitExceeded::DoctypeLength, LimitScope::Token)
+    }
+
+    // The part of text that can be appended to something len bytes long without going over
+    // limit. When not all of it fits, whatever the limit says to do is taken care of here, and
+    // the caller only has to append what is returned.
+    fn fit<'t>(
+        &mut self,
+        text: &'t str,
+        len: usize,
+        limit: Option<Limit>,
+        exceeded: LimitExceeded,
+        scope: LimitScope,
+    ) -> &'t str {
+        let Some(limit) = limit else {
+            return text;
+        };
+        if len + text.len() <= limit.max {
+            return text;
+        }
+        match limit.behavior {
+            LimitBehavior::Truncate => return limits::truncate(text, limit.max.saturating_sub(len)),
+            LimitBehavior::Drop => match scope {
+                LimitScope::Attribute => self.drop_current_attribute = true,
+                LimitScope::Token => self.drop_current_token = true,
+            },
+            LimitBehavior::Fail => self.fail(exceeded),
+        }
+        ""
+    }
+
+    // Stops tokenizing for good. The token that was being built is thrown away, everything
+    // before it still gets handed out.
+    fn fail(&mut self, exceeded: LimitExceeded) {
+        self.limit_exceeded = Some(exceeded);
+        self.current_token = None;
+        self.emit_text_run();
+    }
+
+    // emits the current doctype with its force-quirks flag on, used for the eof cases.
+    fn emit_quirky_doctype(&mut self) {
+        if self.current_token.is_none() {
+            self.start_token(BufferedToken::new_doctype(None));
+        }
+        self.set_force_quirks();
+        self.emit_current_token();
+    }
+
+    fn return_state(&self) -> State {
+        self.return_state.unwrap_or(State::Data)
+    }
+
+    fn consumed_as_part_of_attribute(&self) -> bool {
+        matches!(
+            self.return_state,
+            Some(State::AttributeValueDoubleQuoted)
+                | Some(State::AttributeValueSingleQuoted)
+                | Some(State::AttributeValueUnQuoted)
+        )
+    }
+
+    // Flush code points consumed as a character reference.
+    fn flush_code_points_consumed_as_character_reference(&mut self) {
+        let buffer = std::mem::take(&mut self.temporary_buffer);
+        if self.consumed_as_part_of_attribute() {
+            self.append_str_to_attribute_value(&buffer, self.char_ref_start.offset);
+        } else {
+            self.emit_chars_from(&buffer, self.char_ref_start);
+        }
+    }
+
+    fn add_to_character_reference_code(&mut self, base: u32, digit: char) {
+        // anything above 0x10FFFF is an error anyway, so stop growing to avoid overflowing.
+        let value = digit.to_digit(base).unwrap_or(0);
+        self.character_reference_code = self
+            .character_reference_code
+            .saturating_mul(base)
+            .saturating_add(value)
+            .min(0x110000);
+    }
+
+    // the characters a character reference resolved to, either appended to the attribute value
+    // or emitted as one character token each.
+    fn emit_character_reference(&mut self, chars: &str) {
+        if self.consumed_as_part_of_attribute() {
+            self.append_str_to_attribute_value(chars, self.char_ref_start.offset);
+        } else {
+            let span = Span::new(self.char_ref_start, self.current_end());
+            for c in chars.chars() {
+                self.emit_char_at(c, span);
+            }
+        }
+    }
+
+    // An appropriate end tag token is an end tag token whose tag name matches the tag name of
+    // the last start tag to have been emitted from this tokenizer, if any.
+    fn is_appropriate_end_tag(&self) -> bool {
+        match (&self.current_token, &self.last_start_tag_name) {
+            (Some(BufferedToken::EndTag { tag_name, .. }), Some(last_start_tag_name)) => {
+                tag_name.as_str(&self.input) == last_start_tag_name
+            }
+            _ => false,
+        }
+    }
+
+    // the RCDATA, RAWTEXT and script data end tag open states only differ in where they go next.
+    fn text_end_tag_open(&mut self, end_tag_name_state: State, text_state: State) {
+        match self.consume() {
+            Some(c) if c.is_ascii_alphabetic() => {
+                // Create a new end tag token, set its tag name to the empty string.
+                self.start_token(BufferedToken::new_end_tag(self.char_start.offset));
+                self.reconsume_in(end_tag_name_state);
+            }
+            _ => {
+                // Emit a U+003C LESS-THAN SIGN character token and a U+002F SOLIDUS character
+                // token.
+                self.emit_chars_from("</", self.token_start);
+                self.reconsume_in(text_state);
+            }
+        }
+    }
+
+    // the end tag name states used by RCDATA, RAWTEXT and script data, anything that does not
+    // turn out to be an appropriate end tag goes back to text_state as character tokens.
+    fn text_end_tag_name(&mut self, text_state: State) {
+        match self.consume() {
+            Some('\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}') if self.is_appropriate_end_tag() => {
+                self.current_state = State::BeforeAttributeName;
+            }
+            Some('/') if self.is_appropriate_end_tag() => {
+                self.current_state = State::SelfClosingStartTag;
+            }
+            Some('>') if self.is_appropriate_end_tag() => {
+                self.current_state = State::Data;
+                self.emit_current_token();
+            }
+            Some(c) if c.is_ascii_alphabetic() => {
+                // Append the lowercase version of the current input character to the current tag
+                // token's tag name. Append the current input character to the temporary buffer.
+                self.append_to_tag_name(c.to_ascii_lowercase());
+                self.temporary_buffer.push(c);
+            }
+            _ => {
+                // Emit a U+003C LESS-THAN SIGN character token, a U+002F SOLIDUS character token,
+                // and a character token for each of the characters in the temporary buffer.
+                self.current_token = None;
+                let text = format!("</{}", self.temporary_buffer);
+                self.emit_chars_from(&text, self.token_start);
+                self.reconsume_in(text_state);
+            }
+        }
+    }
+
+    // The double escape start and end states look the same: they collect a tag name in the
+    // temporary buffer and once it ends, "script" means moving to script_state.
+    fn script_data_double_escape_boundary(&mut self, script_state: State, other_state: State) {
+        match self.consume() {
+            Some(c @ ('\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}' | '/' | '>')) => {
+                if self.temporary_buffer == "script" {
+                    self.current_state = script_state;
+                } else {
+                    self.current_state = other_state;
+                }
+                self.emit_char(c);
+            }
+            Some(c) if c.is_ascii_alphabetic() => {
+                self.temporary_buffer.push(c.to_ascii_lowercase());
+                self.emit_char(c);
+            }
+            _ => {
+                self.reconsume_in(other_state);
+            }
+        }
+    }
+
+    // Consumes the template that starts at the next character to be processed, if there is one.
+    // None leaves that character to the state, otherwise the result is the same as for step.
+    fn step_template(&mut self) -> Option<bool> {
+        let start = self.current_end();
+        let rest = self.input.from(start.offset);
+        let Some(index) = self.template_delimiters.iter().position(|delimiter| rest.starts_with(&delimiter.open))
+        else {
+            // wait for the rest of a delimiter that got cut off.
+            let might_open = !self.input.is_ended()
+                && self
+                    .template_delimiters
+                    .iter()
+                    .any(|delimiter| delimiter.open.len() > rest.len() && delimiter.open.starts_with(rest));
+            return if might_open { Some(false) } else { None };
+        };
+        let delimiter = &self.template_delimiters[index];
+
+        // only the input which was not searched yet is looked at.
+        let open_end = start.offset + delimiter.open.len();
+        let (searched_from, searched_to) = self.template_searched[index];
+        let searched = (searched_from..=searched_to).contains(&open_end);
+        // an insertion point can hide the end of what was searched.
+        let from = if searched { searched_to.min(start.offset + rest.len()) } else { open_end };
+        let close = delimiter.close.as_bytes();
+        let len = match memmem::find(&rest.as_bytes()[from - start.offset..], close) {
+            Some(close_start) => from + close_start + close.len() - start.offset,
+            None => {
+                // a close can still start in the last few bytes, once more input follows them.
+                let end = start.offset + rest.len();
+                let to = end.saturating_sub(close.len().saturating_sub(1)).max(from);
+                self.template_searched[index] = (if searched { searched_from } else { open_end }, to);
+                // an opener that is never closed is read as if it was not one.
+                return if self.input.is_ended() { None } else { Some(false) };
+            }
+        };
+
+        // the template goes through the same preprocessing as everything else, so a CR in it
+        // is an LF, and stays a slice of the input as long as it has none. In text it shares the
+        // comment length limit with comments, in an attribute value it is part of the value.
+        let end = start.offset + len;
+        self.reconsume = false;
+        self.cursor = start;
+        let in_data = self.current_state == State::Data;
+        let mut data = Text::at(start.offset);
+        while self.cursor.offset < end {
+            let Some(c) = self.consume() else {
+                break;
+            };
+            if in_data {
+                let len = data.len();
+                let mut buffer = [0; 4];
+                let c = c.encode_utf8(&mut buffer);
+                let c = self.fit(c, len, self.limits.comment_length, LimitExceeded::CommentLength, LimitScope::Token);
+                data.push_str(c, self.char_start.offset, &self.input);
+            } else {
+                self.append_to_attribute_value(c);
+            }
+            if self.limit_exceeded.is_some() {
+                return Some(false);
+            }
+        }
+        if in_data && !std::mem::take(&mut self.drop_current_token) {
+            self.emit(BufferedToken::Template { data }, Span::new(start, self.cursor));
+        }
+        Some(true)
+    }
+
+    // runs the state machine for a single state, which may or may not emit tokens. Returns false
+    // without changing anything when the state needs input that has not been fed yet, or once a
+    // limit has made the tokenizer stop.
+    fn step(&mut self) -> bool {
+        if self.limit_exceeded.is_some() {
+            return false;
+        }
+        if !self.reconsume && self.input.needs_input(self.cursor.offset) {
+            return false;
+        }
+
+        if cfg!(feature = "text-fast-path") && !self.reconsume {
+            let special: Option<&[u8]> = match self.current_state {
+                State::Data | State::RCData => Some(b"<&"),
+                State::RawText | State::ScriptData => Some(b"<"),
+                State::PlainText => Some(b""),
+                _ => None,
+            };
+            if let Some(special) = special
+                && self.emit_text_up_to(special)
+            {
+                return true;
+            }
+        }
+
+        if !self.template_delimiters.is_empty()
+            && matches!(
+                self.current_state,
+                State::Data
+                    | State::AttributeValueDoubleQuoted
+                    | State::AttributeValueSingleQuoted
+                    | State::AttributeValueUnQuoted
+            )
+            && let Some(progress) = self.step_template()
+        {
+            return progress;
+        }
+
+        match self.current_state {
+            State::Data => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '&' => {
+                                self.return_state = Some(State::Data);
+                                self.char_ref_start = self.char_start;
+                                self.current_state = State::CharacterReference;
+                            }
+                            '<' => {
+                                self.token_start = self.char_start;
+                                self.current_state = State::TagOpen;
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.emit_char('\0');
+                            }
+                            other => {
+                                self.emit_char(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::RCData => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '&' => {
+                                self.return_state = Some(State::RCData);
+                                self.char_ref_start = self.char_start;
+                                self.current_state = State::CharacterReference;
+                            }
+                            '<' => {
+                                self.token_start = self.char_start;
+                                self.current_state = State::RCDataLessThan;
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.emit_char('\u{FFFD}');
+                            }
+                            other => {
+                                self.emit_char(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::RawText => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '<' => {
+                                self.token_start = self.char_start;
+                                self.current_state = State::RawTextLessThan;
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.emit_char('\u{FFFD}');
+                            }
+                            other => {
+                                self.emit_char(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::ScriptData => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '<' => {
+                                self.token_start = self.char_start;
+                                self.current_state = State::ScriptDataLessThan;
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.emit_char('\u{FFFD}');
+                            }
+                            other => {
+                                self.emit_char(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::PlainText => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.emit_char('\u{FFFD}');
+                            }
+                            other => {
+                                self.emit_char(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::TagOpen => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '!' => {
+                                self.current_state = State::MarkupDeclarationOpen;
+                            }
+                            '/' => {
+                                self.current_state = State::EndTagOpen;
+                            }
+                            c if c.is_ascii_alphabetic() => {
+                                // Create a new start tag token, set its tag name to the empty string.
+                                // Reconsume in the tag name state.
+                                self.start_token(BufferedToken::new_start_tag(self.char_start.offset));
+                                self.reconsume_in(State::TagName);
+                            }
+                            '?' if self.processing_instructions => {
+                                self.emit_error(ParseError::UnexpectedQuestionMarkInsteadOfTagName);
+                                self.start_token(BufferedToken::new_processing_instruction(self.cursor.offset));
+                                self.current_state = State::ProcessingInstructionTarget;
+                            }
+                            '?' => {
+                                self.emit_error(ParseError::UnexpectedQuestionMarkInsteadOfTagName);
+                                // Create a comment token whose data is the empty string.
+                                // Reconsume in the bogus comment state.
+                                self.start_token(BufferedToken::new_comment(self.char_start.offset));
+                                self.reconsume_in(State::BogusComment);
+                            }
+                            _ => {
+                                self.emit_error(ParseError::InvalidFirstCharacterOfTagName);
+                                // Emit a U+003C LESS-THAN SIGN character token.
+                                // Reconsume in the data state.
+                                self.emit_chars_from("<", self.token_start);
+                                self.reconsume_in(State::Data);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofBeforeTagName);
+                        //emit a less than sign token and then emit an eof token.
+                        self.emit_chars_from("<", self.token_start);
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::EndTagOpen => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            c if c.is_ascii_alphabetic() => {
+                                //Create a new end tag token, set its tag name to the empty string.
+                                //Reconsume in the tag name state.
+                                self.start_token(BufferedToken::new_end_tag(self.char_start.offset));
+                                self.reconsume_in(State::TagName);
+                            }
+                            '>' => {
+                                self.emit_error(ParseError::MissingEndTagName);
+                                self.current_state = State::Data;
+                            }
+                            _ => {
+                                self.emit_error(ParseError::InvalidFirstCharacterOfTagName);
+                                //Create a comment token whose data is the empty string.
+                                //Reconsume in the bogus comment state.
+                                self.start_token(BufferedToken::new_comment(self.char_start.offset));
+                                self.reconsume_in(State::BogusComment);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofBeforeTagName);
+                        // emit less than char token, then solidus char token, then an eof token.
+                        self.emit_chars_from("</", self.token_start);
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::TagName => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}' => {
+                                self.current_state = State::BeforeAttributeName;
+                            }
+                            '/' => {
+                                self.current_state = State::SelfClosingStartTag;
+                            }
+                            '>' => {
+                                self.current_state = State::Data;
+                                self.emit_current_token();
+                            }
+                            c if c.is_ascii_uppercase() => {
+                                // Append the lowercase version of the current input character
+                                // to the current tag token's tag name.
+                                self.append_to_tag_name(c.to_ascii_lowercase());
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.append_to_tag_name('\u{FFFD}');
+                            }
+                            other => {
+                                self.append_to_tag_name(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInTag);
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::RCDataLessThan => {
+                match self.consume() {
+                    Some('/') => {
+                        // Set the temporary buffer to the empty string.
+                        // Switch to the RCDATA end tag open state.
+                        self.temporary_buffer.clear();
+                        self.current_state = State::RCDataEndTagOpen;
+                    }
+                    _ => {
+                        // Emit a U+003C LESS-THAN SIGN character token. Reconsume in the RCDATA state.
+                        self.emit_chars_from("<", self.token_start);
+                        self.reconsume_in(State::RCData);
+                    }
+                }
+            }
+
+            State::RCDataEndTagOpen => {
+                self.text_end_tag_open(State::RCDataEndTagName, State::RCData);
+            }
+
+            State::RCDataEndTagName => {
+                self.text_end_tag_name(State::RCData);
+            }
+
+            State::RawTextLessThan => {
+                match self.consume() {
+                    Some('/') => {
+                        // Set the temporary buffer to the empty string.
+                        // Switch to the RAWTEXT end tag open state.
+                        self.temporary_buffer.clear();
+                        self.current_state = State::RawTextEndOpen;
+                    }
+                    _ => {
+                        // Emit a U+003C LESS-THAN SIGN character token.
+                        // Reconsume in the RAWTEXT state.
+                        self.emit_chars_from("<", self.token_start);
+                        self.reconsume_in(State::RawText);
+                    }
+                }
+            }
+
+            State::RawTextEndOpen => {
+                self.text_end_tag_open(State::RawTextEndTagName, State::RawText);
+            }
+
+            State::RawTextEndTagName => {
+                self.text_end_tag_name(State::RawText);
+            }
+
+            State::ScriptDataLessThan => {
+                match self.consume() {
+                    Some('/') => {
+                        self.temporary_buffer.clear();
+                        self.current_state = State::ScriptDataEndTagOpen;
+                    }
+                    Some('!') => {
+                        self.current_state = State::ScriptDataEscapeStart;
+                        self.emit_chars_from("<!", self.token_start);
+                    }
+                    _ => {
+                        self.emit_chars_from("<", self.token_start);
+                        self.reconsume_in(State::ScriptData);
+                    }
+                }
+            }
+
+            State::ScriptDataEndTagOpen => {
+                self.text_end_tag_open(State::ScriptDataEndTagName, State::ScriptData);
+            }
+
+            State::ScriptDataEndTagName => {
+                self.text_end_tag_name(State::ScriptData);
+            }
+
+            State::ScriptDataEscapeStart => {
+                match self.consume() {
+                    Some('-') => {
+                        self.current_state = State::ScriptDataEscapeStartDash;
+                        self.emit_char('-');
+                    }
+                    _ => {
+                        self.reconsume_in(State::ScriptData);
+                    }
+                }
+            }
+
+            State::ScriptDataEscapeStartDash => {
+                match self.consume() {
+                    Some('-') => {
+                        self.current_state = State::ScriptDataEscapedDashDash;
+                        self.emit_char('-');
+                    }
+                    _ => {
+                        self.reconsume_in(State::ScriptData);
+                    }
+                }
+            }
+
+            State::ScriptDataEscaped => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '-' => {
+                                self.current_state = State::ScriptDataEscapedDash;
+                                self.emit_char('-');
+                            }
+                            '<' => {
+                                self.token_start = self.char_start;
+                                self.current_state = State::ScriptDataEscapedLessThan;
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.emit_char('\u{FFFD}');
+                            }
+                            other => {
+                                self.emit_char(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInScriptHtmlCommentLikeText);
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::ScriptDataEscapedDash => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '-' => {
+                                self.current_state = State::ScriptDataEscapedDashDash;
+                                self.emit_char('-');
+                            }
+                            '<' => {
+                                self.token_start = self.char_start;
+                                self.current_state = State::ScriptDataEscapedLessThan;
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.current_state = State::ScriptDataEscaped;
+                                self.emit_char('\u{FFFD}');
+                            }
+                            other => {
+                                self.current_state = State::ScriptDataEscaped;
+                                self.emit_char(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInScriptHtmlCommentLikeText);
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::ScriptDataEscapedDashDash => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '-' => {
+                                self.emit_char('-');
+                            }
+                            '<' => {
+                                self.token_start = self.char_start;
+                                self.current_state = State::ScriptDataEscapedLessThan;
+                            }
+                            '>' => {
+                                self.current_state = State::ScriptData;
+                                self.emit_char('>');
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.current_state = State::ScriptDataEscaped;
+                                self.emit_char('\u{FFFD}');
+                            }
+                            other => {
+                                self.current_state = State::ScriptDataEscaped;
+                                self.emit_char(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInScriptHtmlCommentLikeText);
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::ScriptDataEscapedLessThan => {
+                match self.consume() {
+                    Some('/') => {
+                        self.temporary_buffer.clear();
+                        self.current_state = State::ScriptDataEscapedEndTagOpen;
+                    }
+                    Some(c) if c.is_ascii_alphabetic() => {
+                        // a "<script" inside the escaped text starts the double escaped states.
+                        self.temporary_buffer.clear();
+                        self.emit_chars_from("<", self.token_start);
+                        self.reconsume_in(State::ScriptDataDoubleEscapeStart);
+                    }
+                    _ => {
+                        self.emit_chars_from("<", self.token_start);
+                        self.reconsume_in(State::ScriptDataEscaped);
+                    }
+                }
+            }
+
+            State::ScriptDataEscapedEndTagOpen => {
+                self.text_end_tag_open(State::ScriptDataEscapedEndTagName, State::ScriptDataEscaped);
+            }
+
+            State::ScriptDataEscapedEndTagName => {
+                self.text_end_tag_name(State::ScriptDataEscaped);
+            }
+
+            State::ScriptDataDoubleEscapeStart => {
+                self.script_data_double_escape_boundary(
+                    State::ScriptDataDoubleEscaped,
+                    State::ScriptDataEscaped,
+                );
+            }
+
+            State::ScriptDataDoubleEscaped => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '-' => {
+                                self.current_state = State::ScriptDataDoubleEscapedDash;
+                                self.emit_char('-');
+                            }
+                            '<' => {
+                                self.current_state = State::ScriptDataDoubleEscapedLessThan;
+                                self.emit_char('<');
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.emit_char('\u{FFFD}');
+                            }
+                            other => {
+                                self.emit_char(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInScriptHtmlCommentLikeText);
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::ScriptDataDoubleEscapedDash => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '-' => {
+                                self.current_state = State::ScriptDataDoubleEscapedDashDash;
+                                self.emit_char('-');
+                            }
+                            '<' => {
+                                self.current_state = State::ScriptDataDoubleEscapedLessThan;
+                                self.emit_char('<');
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.current_state = State::ScriptDataDoubleEscaped;
+                                self.emit_char('\u{FFFD}');
+                            }
+                            other => {
+                                self.current_state = State::ScriptDataDoubleEscaped;
+                                self.emit_char(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInScriptHtmlCommentLikeText);
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::ScriptDataDoubleEscapedDashDash => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '-' => {
+                                self.emit_char('-');
+                            }
+                            '<' => {
+                                self.current_state = State::ScriptDataDoubleEscapedLessThan;
+                                self.emit_char('<');
+                            }
+                            '>' => {
+                                self.current_state = State::ScriptData;
+                                self.emit_char('>');
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.current_state = State::ScriptDataDoubleEscaped;
+                                self.emit_char('\u{FFFD}');
+                            }
+                            other => {
+                                self.current_state = State::ScriptDataDoubleEscaped;
+                                self.emit_char(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInScriptHtmlCommentLikeText);
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::ScriptDataDoubleEscapedLessThan => {
+                match self.consume() {
+                    Some('/') => {
+                        self.temporary_buffer.clear();
+                        self.current_state = State::ScriptDataDoubleEscapeEnd;
+                        self.emit_char('/');
+                    }
+                    _ => {
+                        self.reconsume_in(State::ScriptDataDoubleEscaped);
+                    }
+                }
+            }
+
+            State::ScriptDataDoubleEscapeEnd => {
+                self.script_data_double_escape_boundary(
+                    State::ScriptDataEscaped,
+                    State::ScriptDataDoubleEscaped,
+                );
+            }
+
+            State::BeforeAttributeName => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}' => {
+                                //ignore
+                            }
+                            '/' | '>' => {
+                                self.reconsume_in(State::AfterAttributeName);
+                            }
+                            '=' => {
+                                self.emit_error(ParseError::UnexpectedEqualsSignBeforeAttributeName);
+                                // Start a new attribute whose name is the current input character.
+                                self.start_new_attribute("=");
+                                self.current_state = State::AttributeName;
+                            }
+                            _ => {
+                                self.start_new_attribute("");
+                                self.reconsume_in(State::AttributeName);
+                            }
+                        }
+                    }
+                    None => {
+                        self.reconsume_in(State::AfterAttributeName);
+                    }
+                }
+            }
+
+            State::AttributeName => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}' | '/' | '>' => {
+                                self.check_duplicate_attribute();
+                                self.reconsume_in(State::AfterAttributeName);
+                            }
+                            '=' => {
+                                self.check_duplicate_attribute();
+                                self.current_state = State::BeforeAttributeValue;
+                            }
+                            c if c.is_ascii_uppercase() => {
+                                self.append_to_attribute_name(c.to_ascii_lowercase());
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.append_to_attribute_name('\u{FFFD}');
+                            }
+                            '"' | '\'' | '<' => {
+                                self.emit_error(ParseError::UnexpectedCharacterInAttributeName);
+                                self.append_to_attribute_name(c);
+                            }
+                            other => {
+                                self.append_to_attribute_name(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.check_duplicate_attribute();
+                        self.reconsume_in(State::AfterAttributeName);
+                    }
+                }
+            }
+
+            State::AfterAttributeName => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}' => {
+                                //ignore
+                            }
+                            '/' => {
+                                self.current_state = State::SelfClosingStartTag;
+                            }
+                            '=' => {
+                                self.current_state = State::BeforeAttributeValue;
+                            }
+                            '>' => {
+                                self.current_state = State::Data;
+                                self.emit_current_token();
+                            }
+                            _ => {
+                                self.start_new_attribute("");
+                                self.reconsume_in(State::AttributeName);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInTag);
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::BeforeAttributeValue => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}' => {
+                                //ignore
+                            }
+                            '"' => {
+                                self.current_state = State::AttributeValueDoubleQuoted;
+                            }
+                            '\'' => {
+                                self.current_state = State::AttributeValueSingleQuoted;
+                            }
+                            '>' => {
+                                self.emit_error(ParseError::MissingAttributeValue);
+                                self.current_state = State::Data;
+                                self.emit_current_token();
+                            }
+                            _ => {
+                                self.reconsume_in(State::AttributeValueUnQuoted);
+                            }
+                        }
+                    }
+                    None => {
+                        self.reconsume_in(State::AttributeValueUnQuoted);
+                    }
+                }
+            }
+
+            State::AttributeValueDoubleQuoted => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '"' => {
+                                self.current_state = State::AfterAttributeValueQuoted;
+                            }
+                            '&' => {
+                                self.return_state = Some(State::AttributeValueDoubleQuoted);
+                                self.char_ref_start = self.char_start;
+                                self.current_state = State::CharacterReference;
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.append_to_attribute_value('\u{FFFD}');
+                            }
+                            other => {
+                                self.append_to_attribute_value(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInTag);
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::AttributeValueSingleQuoted => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\'' => {
+                                self.current_state = State::AfterAttributeValueQuoted;
+                            }
+                            '&' => {
+                                self.return_state = Some(State::AttributeValueSingleQuoted);
+                                self.char_ref_start = self.char_start;
+                                self.current_state = State::CharacterReference;
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.append_to_attribute_value('\u{FFFD}');
+                            }
+                            other => {
+                                self.append_to_attribute_value(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInTag);
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::AttributeValueUnQuoted => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}' => {
+                                self.current_state = State::BeforeAttributeName;
+                            }
+                            '&' => {
+                                self.return_state = Some(State::AttributeValueUnQuoted);
+                                self.char_ref_start = self.char_start;
+                                self.current_state = State::CharacterReference;
+                            }
+                            '>' => {
+                                self.current_state = State::Data;
+                                self.emit_current_token();
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.append_to_attribute_value('\u{FFFD}');
+                            }
+                            '"' | '\'' | '<' | '=' | '`' => {
+                                self.emit_error(ParseError::UnexpectedCharacterInUnquotedAttributeValue);
+                                self.append_to_attribute_value(c);
+                            }
+                            other => {
+                                self.append_to_attribute_value(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInTag);
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::AfterAttributeValueQuoted => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}' => {
+                                self.current_state = State::BeforeAttributeName;
+                            }
+                            '/' => {
+                                self.current_state = State::SelfClosingStartTag;
+                            }
+                            '>' => {
+                                self.current_state = State::Data;
+                                self.emit_current_token();
+                            }
+                            _ => {
+                                self.emit_error(ParseError::MissingWhitespaceBetweenAttributes);
+                                self.reconsume_in(State::BeforeAttributeName);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInTag);
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::SelfClosingStartTag => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '>' => {
+                                self.set_self_closing();
+                                self.current_state = State::Data;
+                                self.emit_current_token();
+                            }
+                            _ => {
+                                self.emit_error(ParseError::UnexpectedSolidusInTag);
+                                self.reconsume_in(State::BeforeAttributeName);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInTag);
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::BogusComment => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '>' => {
+                                self.current_state = State::Data;
+                                self.emit_current_token();
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.append_to_comment("\u{FFFD}");
+                            }
+                            other => {
+                                self.append_to_comment(other.encode_utf8(&mut [0; 4]));
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_current_token();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            // The target runs up to the first whitespace, the data from there to the "?>" without
+            // the whitespace in front of it, the same as in XML.
+            State::ProcessingInstructionTarget => {
+                match self.consume() {
+                    Some('\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}') => {
+                        self.current_state = State::ProcessingInstructionData;
+                    }
+                    Some('?') => {
+                        self.current_state = State::ProcessingInstructionQuestionMark;
+                    }
+                    // "<?>" is an empty instruction, the same as the empty bogus comment it would
+                    // be otherwise.
+                    Some('>') if self.processing_instruction_target_is_empty() => {
+                        self.current_state = State::Data;
+                        self.emit_current_token();
+                    }
+                    Some('\0') => {
+                        self.emit_error(ParseError::UnexpectedNullCharacter);
+                        self.append_to_processing_instruction(true, '\u{FFFD}');
+                    }
+                    Some(c) => {
+                        self.append_to_processing_instruction(true, c);
+                    }
+                    None => {
+                        self.reread_processing_instruction_as_bogus_comment();
+                    }
+                }
+            }
+
+            State::ProcessingInstructionData => {
+                match self.consume() {
+                    Some('?') => {
+                        self.current_state = State::ProcessingInstructionQuestionMark;
+                    }
+                    Some('\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}')
+                        if self.processing_instruction_data_is_empty() =>
+                    {
+                        //ignore
+                    }
+                    Some('\0') => {
+                        self.emit_error(ParseError::UnexpectedNullCharacter);
+                        self.append_to_processing_instruction(false, '\u{FFFD}');
+                    }
+                    Some(c) => {
+                        self.append_to_processing_instruction(false, c);
+                    }
+                    None => {
+                        self.reread_processing_instruction_as_bogus_comment();
+                    }
+                }
+            }
+
+            State::ProcessingInstructionQuestionMark => {
+                match self.consume() {
+                    Some('>') => {
+                        self.current_state = State::Data;
+                        self.emit_current_token();
+                    }
+                    _ => {
+                        // a "?" that does not end the instruction is data, even right after the
+                        // target.
+                        let question_mark = self.char_start.offset - 1;
+                        self.append_to_processing_instruction_at(false, '?', question_mark);
+                        self.reconsume_in(State::ProcessingInstructionData);
+                    }
+                }
+            }
+
+            State::MarkupDeclarationOpen => {
+                if self.might_become(&["--", "DOCTYPE", "[CDATA["]) {
+                    return false;
+                }
+
+                if self.next_chars_are("--", false) {
+                    self.consume_matched("--");
+                    self.start_token(BufferedToken::new_comment(self.cursor.offset));
+                    self.current_state = State::CommentStart;
+                } else if self.next_chars_are("DOCTYPE", true) {
+                    self.consume_matched("DOCTYPE");
+                    self.current_state = State::Doctype;
+                } else if self.next_chars_are("[CDATA[", false) {
+                    self.consume_matched("[CDATA[");
+                    if self.in_foreign_content {
+                        self.current_state = State::CDataSection;
+                    } else {
+                        self.emit_error(ParseError::CDataInHtmlContent);
+                        self.start_token(BufferedToken::new_comment(self.cursor.offset - "[CDATA[".len()));
+                        self.append_to_comment("[CDATA[");
+                        self.current_state = State::BogusComment;
+                    }
+                } else {
+                    self.emit_error(ParseError::IncorrectlyOpenedComment);
+                    self.start_token(BufferedToken::new_comment(self.cursor.offset));
+                    self.current_state = State::BogusComment;
+                }
+            }
+
+            State::CommentStart => {
+                match self.consume() {
+                    Some('-') => {
+                        self.current_state = State::CommentStartDash;
+                    }
+                    Some('>') => {
+                        self.emit_error(ParseError::AbruptClosingOfEmptyComment);
+                        self.current_state = State::Data;
+                        self.emit_current_token();
+                    }
+                    _ => {
+                        self.reconsume_in(State::Comment);
+                    }
+                }
+            }
+
+            State::CommentStartDash => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '-' => {
+                                self.current_state = State::CommentEnd;
+                            }
+                            '>' => {
+                                self.emit_error(ParseError::AbruptClosingOfEmptyComment);
+                                self.current_state = State::Data;
+                                self.emit_current_token();
+                            }
+                            _ => {
+                                self.append_to_comment("-");
+                                self.reconsume_in(State::Comment);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInComment);
+                        self.emit_current_token();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::Comment => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '<' => {
+                                self.append_to_comment("<");
+                                self.current_state = State::CommentLessThan;
+                            }
+                            '-' => {
+                                self.current_state = State::CommentEndDash;
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.append_to_comment("\u{FFFD}");
+                            }
+                            other => {
+                                self.append_to_comment(other.encode_utf8(&mut [0; 4]));
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInComment);
+                        self.emit_current_token();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::CommentLessThan => {
+                match self.consume() {
+                    Some('!') => {
+                        self.append_to_comment("!");
+                        self.current_state = State::CommentLessThanBang;
+                    }
+                    Some('<') => {
+                        self.append_to_comment("<");
+                    }
+                    _ => {
+                        self.reconsume_in(State::Comment);
+                    }
+                }
+            }
+
+            State::CommentLessThanBang => {
+                match self.consume() {
+                    Some('-') => {
+                        self.current_state = State::CommentLessThanBangDash;
+                    }
+                    _ => {
+                        self.reconsume_in(State::Comment);
+                    }
+                }
+            }
+
+            State::CommentLessThanBangDash => {
+                match self.consume() {
+                    Some('-') => {
+                        self.current_state = State::CommentLessThanBangDashDash;
+                    }
+                    _ => {
+                        self.reconsume_in(State::CommentEndDash);
+                    }
+                }
+            }
+
+            State::CommentLessThanBangDashDash => {
+                match self.consume() {
+                    Some('>') | None => {
+                        self.reconsume_in(State::CommentEnd);
+                    }
+                    Some(_) => {
+                        self.emit_error(ParseError::NestedComment);
+                        self.reconsume_in(State::CommentEnd);
+                    }
+                }
+            }
+
+            State::CommentEndDash => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '-' => {
+                                self.current_state = State::CommentEnd;
+                            }
+                            _ => {
+                                self.append_to_comment("-");
+                                self.reconsume_in(State::Comment);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInComment);
+                        self.emit_current_token();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::CommentEnd => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '>' => {
+                                self.current_state = State::Data;
+                                self.emit_current_token();
+                            }
+                            '!' => {
+                                self.current_state = State::CommentEndBang;
+                            }
+                            '-' => {
+                                self.append_to_comment("-");
+                            }
+                            _ => {
+                                self.append_to_comment("--");
+                                self.reconsume_in(State::Comment);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInComment);
+                        self.emit_current_token();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::CommentEndBang => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '-' => {
+                                self.append_to_comment("--!");
+                                self.current_state = State::CommentEndDash;
+                            }
+                            '>' => {
+                                self.emit_error(ParseError::IncorrectlyClosedComment);
+                                self.current_state = State::Data;
+                                self.emit_current_token();
+                            }
+                            _ => {
+                                self.append_to_comment("--!");
+                                self.reconsume_in(State::Comment);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInComment);
+                        self.emit_current_token();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::Doctype => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}' => {
+                                self.current_state = State::BeforeDoctypeName;
+                            }
+                            '>' => {
+                                self.reconsume_in(State::BeforeDoctypeName);
+                            }
+                            _ => {
+                                self.emit_error(ParseError::MissingWhitespaceBeforeDoctypeName);
+                                self.reconsume_in(State::BeforeDoctypeName);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInDoctype);
+                        self.emit_quirky_doctype();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::BeforeDoctypeName => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}' => {
+                                //ignore
+                            }
+                            c if c.is_ascii_uppercase() => {
+                                self.start_token(BufferedToken::new_doctype(Some(c.to_ascii_lowercase().to_string())));
+                                self.current_state = State::DoctypeName;
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.start_token(BufferedToken::new_doctype(Some('\u{FFFD}'.to_string())));
+                                self.current_state = State::DoctypeName;
+                            }
+                            '>' => {
+                                self.emit_error(ParseError::MissingDoctypeName);
+                                self.current_state = State::Data;
+                                self.emit_quirky_doctype();
+                            }
+                            other => {
+                                self.start_token(BufferedToken::new_doctype(Some(other.to_string())));
+                                self.current_state = State::DoctypeName;
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInDoctype);
+                        self.emit_quirky_doctype();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::DoctypeName => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}' => {
+                                self.current_state = State::AfterDoctypeName;
+                            }
+                            '>' => {
+                                self.current_state = State::Data;
+                                self.emit_current_token();
+                            }
+                            c if c.is_ascii_uppercase() => {
+                                self.append_to_doctype_name(c.to_ascii_lowercase());
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.append_to_doctype_name('\u{FFFD}');
+                            }
+                            other => {
+                                self.append_to_doctype_name(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInDoctype);
+                        self.emit_quirky_doctype();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::AfterDoctypeName => {
+                if self.might_become(&["PUBLIC", "SYSTEM"]) {
+                    return false;
+                }
+
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}' => {
+                                //ignore
+                            }
+                            '>' => {
+                                self.current_state = State::Data;
+                                self.emit_current_token();
+                            }
+                            // the current input character is the first of the six characters
+                            // of the "PUBLIC" or "SYSTEM" keyword.
+                            'p' | 'P' if self.next_chars_are("UBLIC", true) => {
+                                self.consume_matched("UBLIC");
+                                self.current_state = State::AfterDoctypePublicKeyword;
+                            }
+                            's' | 'S' if self.next_chars_are("YSTEM", true) => {
+                                self.consume_matched("YSTEM");
+                                self.current_state = State::AfterDoctypeSystemKeyword;
+                            }
+                            _ => {
+                                self.emit_error(ParseError::InvalidCharacterSequenceAfterDoctypeName);
+                                self.set_force_quirks();
+                                self.reconsume_in(State::BogusDoctype);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInDoctype);
+                        self.emit_quirky_doctype();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::AfterDoctypePublicKeyword => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}' => {
+                                self.current_state = State::BeforeDoctypePublicIdentifier;
+                            }
+                            '"' => {
+                                self.emit_error(ParseError::MissingWhitespaceAfterDoctypePublicKeyword);
+                                self.set_public_identifier_empty();
+                                self.current_state = State::DoctypePublicIdentifierDoubleQuoted;
+                            }
+                            '\'' => {
+                                self.emit_error(ParseError::MissingWhitespaceAfterDoctypePublicKeyword);
+                                self.set_public_identifier_empty();
+                                self.current_state = State::DoctypePublicIdentifierSingleQuoted;
+                            }
+                            '>' => {
+                                self.emit_error(ParseError::MissingDoctypePublicIdentifier);
+                                self.current_state = State::Data;
+                                self.emit_quirky_doctype();
+                            }
+                            _ => {
+                                self.emit_error(ParseError::MissingQuoteBeforeDoctypePublicIdentifier);
+                                self.set_force_quirks();
+                                self.reconsume_in(State::BogusDoctype);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInDoctype);
+                        self.emit_quirky_doctype();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::BeforeDoctypePublicIdentifier => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}' => {
+                                //ignore
+                            }
+                            '"' => {
+                                self.set_public_identifier_empty();
+                                self.current_state = State::DoctypePublicIdentifierDoubleQuoted;
+                            }
+                            '\'' => {
+                                self.set_public_identifier_empty();
+                                self.current_state = State::DoctypePublicIdentifierSingleQuoted;
+                            }
+                            '>' => {
+                                self.emit_error(ParseError::MissingDoctypePublicIdentifier);
+                                self.current_state = State::Data;
+                                self.emit_quirky_doctype();
+                            }
+                            _ => {
+                                self.emit_error(ParseError::MissingQuoteBeforeDoctypePublicIdentifier);
+                                self.set_force_quirks();
+                                self.reconsume_in(State::BogusDoctype);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInDoctype);
+                        self.emit_quirky_doctype();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::DoctypePublicIdentifierDoubleQuoted => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '"' => {
+                                self.current_state = State::AfterDoctypePublicIdentifier;
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.append_to_public_identifier('\u{FFFD}');
+                            }
+                            '>' => {
+                                self.emit_error(ParseError::AbruptDoctypePublicIdentifier);
+                                self.current_state = State::Data;
+                                self.emit_quirky_doctype();
+                            }
+                            other => {
+                                self.append_to_public_identifier(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInDoctype);
+                        self.emit_quirky_doctype();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::DoctypePublicIdentifierSingleQuoted => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\'' => {
+                                self.current_state = State::AfterDoctypePublicIdentifier;
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.append_to_public_identifier('\u{FFFD}');
+                            }
+                            '>' => {
+                                self.emit_error(ParseError::AbruptDoctypePublicIdentifier);
+                                self.current_state = State::Data;
+                                self.emit_quirky_doctype();
+                            }
+                            other => {
+                                self.append_to_public_identifier(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInDoctype);
+                        self.emit_quirky_doctype();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::AfterDoctypePublicIdentifier => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}' => {
+                                self.current_state = State::BetweenDoctypePublicAndSystemIdentifiers;
+                            }
+                            '>' => {
+                                self.current_state = State::Data;
+                                self.emit_current_token();
+                            }
+                            '"' => {
+                                self.emit_error(ParseError::MissingWhitespaceBetweenDoctypePublicAndSystemIdentifiers);
+                                self.set_system_identifier_empty();
+                                self.current_state = State::DoctypeSystemIdentifierDoubleQuoted;
+                            }
+                            '\'' => {
+                                self.emit_error(ParseError::MissingWhitespaceBetweenDoctypePublicAndSystemIdentifiers);
+                                self.set_system_identifier_empty();
+                                self.current_state = State::DoctypeSystemIdentifierSingleQuoted;
+                            }
+                            _ => {
+                                self.emit_error(ParseError::MissingQuoteBeforeDoctypeSystemIdentifier);
+                                self.set_force_quirks();
+                                self.reconsume_in(State::BogusDoctype);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInDoctype);
+                        self.emit_quirky_doctype();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::BetweenDoctypePublicAndSystemIdentifiers => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}' => {
+                                //ignore
+                            }
+                            '>' => {
+                                self.current_state = State::Data;
+                                self.emit_current_token();
+                            }
+                            '"' => {
+                                self.set_system_identifier_empty();
+                                self.current_state = State::DoctypeSystemIdentifierDoubleQuoted;
+                            }
+                            '\'' => {
+                                self.set_system_identifier_empty();
+                                self.current_state = State::DoctypeSystemIdentifierSingleQuoted;
+                            }
+                            _ => {
+                                self.emit_error(ParseError::MissingQuoteBeforeDoctypeSystemIdentifier);
+                                self.set_force_quirks();
+                                self.reconsume_in(State::BogusDoctype);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInDoctype);
+                        self.emit_quirky_doctype();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::AfterDoctypeSystemKeyword => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}' => {
+                                self.current_state = State::BeforeDoctypeSystemIdentifier;
+                            }
+                            '"' => {
+                                self.emit_error(ParseError::MissingWhitespaceAfterDoctypeSystemKeyword);
+                                self.set_system_identifier_empty();
+                                self.current_state = State::DoctypeSystemIdentifierDoubleQuoted;
+                            }
+                            '\'' => {
+                                self.emit_error(ParseError::MissingWhitespaceAfterDoctypeSystemKeyword);
+                                self.set_system_identifier_empty();
+                                self.current_state = State::DoctypeSystemIdentifierSingleQuoted;
+                            }
+                            '>' => {
+                                self.emit_error(ParseError::MissingDoctypeSystemIdentifier);
+                                self.current_state = State::Data;
+                                self.emit_quirky_doctype();
+                            }
+                            _ => {
+                                self.emit_error(ParseError::MissingQuoteBeforeDoctypeSystemIdentifier);
+                                self.set_force_quirks();
+                                self.reconsume_in(State::BogusDoctype);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInDoctype);
+                        self.emit_quirky_doctype();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::BeforeDoctypeSystemIdentifier => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}' => {
+                                //ignore
+                            }
+                            '"' => {
+                                self.set_system_identifier_empty();
+                                self.current_state = State::DoctypeSystemIdentifierDoubleQuoted;
+                            }
+                            '\'' => {
+                                self.set_system_identifier_empty();
+                                self.current_state = State::DoctypeSystemIdentifierSingleQuoted;
+                            }
+                            '>' => {
+                                self.emit_error(ParseError::MissingDoctypeSystemIdentifier);
+                                self.current_state = State::Data;
+                                self.emit_quirky_doctype();
+                            }
+                            _ => {
+                                self.emit_error(ParseError::MissingQuoteBeforeDoctypeSystemIdentifier);
+                                self.set_force_quirks();
+                                self.reconsume_in(State::BogusDoctype);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInDoctype);
+                        self.emit_quirky_doctype();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::DoctypeSystemIdentifierDoubleQuoted => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '"' => {
+                                self.current_state = State::AfterDoctypeSystemIdentifier;
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.append_to_system_identifier('\u{FFFD}');
+                            }
+                            '>' => {
+                                self.emit_error(ParseError::AbruptDoctypeSystemIdentifier);
+                                self.current_state = State::Data;
+                                self.emit_quirky_doctype();
+                            }
+                            other => {
+                                self.append_to_system_identifier(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInDoctype);
+                        self.emit_quirky_doctype();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::DoctypeSystemIdentifierSingleQuoted => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\'' => {
+                                self.current_state = State::AfterDoctypeSystemIdentifier;
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                self.append_to_system_identifier('\u{FFFD}');
+                            }
+                            '>' => {
+                                self.emit_error(ParseError::AbruptDoctypeSystemIdentifier);
+                                self.current_state = State::Data;
+                                self.emit_quirky_doctype();
+                            }
+                            other => {
+                                self.append_to_system_identifier(other);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInDoctype);
+                        self.emit_quirky_doctype();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::AfterDoctypeSystemIdentifier => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | '\u{0020}' => {
+                                //ignore
+                            }
+                            '>' => {
+                                self.current_state = State::Data;
+                                self.emit_current_token();
+                            }
+                            _ => {
+                                self.emit_error(ParseError::UnexpectedCharacterAfterDoctypeSystemIdentifier);
+                                // this does not set the force-quirks flag.
+                                self.reconsume_in(State::BogusDoctype);
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInDoctype);
+                        self.emit_quirky_doctype();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::BogusDoctype => {
+                match self.consume() {
+                    Some(c) => {
+                        match c {
+                            '>' => {
+                                self.current_state = State::Data;
+                                self.emit_current_token();
+                            }
+                            '\0' => {
+                                self.emit_error(ParseError::UnexpectedNullCharacter);
+                                // ignore
+                            }
+                            _ => {
+                                //ignore
+                            }
+                        }
+                    }
+                    None => {
+                        self.emit_current_token();
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::CharacterReference => {
+                self.temporary_buffer.clear();
+                self.temporary_buffer.push('&');
+                match self.consume() {
+                    Some(c) if c.is_ascii_alphanumeric() => {
+                        self.reconsume_in(State::NamedCharacterReference);
+                    }
+                    Some('#') => {
+                        self.temporary_buffer.push('#');
+                        self.current_state = State::NumericCharacterReference;
+                    }
+                    _ => {
+                        self.flush_code_points_consumed_as_character_reference();
+                        self.reconsume_in(self.return_state());
+                    }
+                }
+            }
+
+            State::NamedCharacterReference => {
+                // we got here by reconsuming, so the match starts at the current input character.
+                let rest = self.input.from(self.char_start.offset);
+                let (longest_match, input_exhausted) = entities::longest_match(rest);
+                if input_exhausted && !self.input.is_ended() {
+                    // a longer name might still match once more input arrives.
+                    return false;
+                }
+
+                match longest_match {
+                    Some((name, chars)) => {
+                        // Consume the maximum number of characters possible, where the consumed
+                        // characters are one of the identifiers in the table.
+                        self.reconsume = false;
+                        self.cursor = self.char_start;
+                        self.consume_matched(name);
+                        self.temporary_buffer.push_str(name);
+
+                        let next_char = self.remaining_input().chars().next();
+                        if self.consumed_as_part_of_attribute()
+                            && !name.ends_with(';')
+                            && next_char.is_some_and(|c| c == '=' || c.is_ascii_alphanumeric())
+                        {
+                            // for historical reasons "&notit=" inside an attribute value is
+                            // left as it is.
+                            self.flush_code_points_consumed_as_character_reference();
+                        } else {
+                            if !name.ends_with(';') {
+                                self.emit_error(ParseError::MissingSemicolonAfterCharacterReference);
+                            }
+                            self.temporary_buffer.clear();
+                            self.emit_character_reference(chars);
+                        }
+                        self.current_state = self.return_state();
+                    }
+                    None => {
+                        self.flush_code_points_consumed_as_character_reference();
+                        self.current_state = State::AmbiguousAmpersand;
+                    }
+                }
+            }
+
+            State::AmbiguousAmpersand => {
+                match self.consume() {
+                    Some(c) if c.is_ascii_alphanumeric() => {
+                        if self.consumed_as_part_of_attribute() {
+                            self.append_to_attribute_value(c);
+                        } else {
+                            self.emit_char(c);
+                        }
+                    }
+                    Some(';') => {
+                        self.emit_error(ParseError::UnknownNamedCharacterReference);
+                        self.reconsume_in(self.return_state());
+                    }
+                    _ => {
+                        self.reconsume_in(self.return_state());
+                    }
+                }
+            }
+
+            State::NumericCharacterReference => {
+                self.character_reference_code = 0;
+                match self.consume() {
+                    Some(c) if c == '\u{0078}' || c == '\u{0058}' => {
+                        self.temporary_buffer.push(c);
+                        self.current_state = State::HexadecimalCharacterReferenceStart;
+                    }
+                    _ => {
+                        self.reconsume_in(State::DecimalCharacterReferenceStart);
+                    }
+                }
+            }
+
+            State::HexadecimalCharacterReferenceStart => {
+                match self.consume() {
+                    Some(c) if c.is_ascii_hexdigit() => {
+                        self.reconsume_in(State::HexadecimalCharacterReference);
+                    }
+                    _ => {
+                        self.emit_error(ParseError::AbsenceOfDigitsInNumericCharacterReference);
+                        self.flush_code_points_consumed_as_character_reference();
+                        self.reconsume_in(self.return_state());
+                    }
+                }
+            }
+
+            State::DecimalCharacterReferenceStart => {
+                match self.consume() {
+                    Some(c) if c.is_ascii_digit() => {
+                        self.reconsume_in(State::DecimalCharacterReference);
+                    }
+                    _ => {
+                        self.emit_error(ParseError::AbsenceOfDigitsInNumericCharacterReference);
+                        self.flush_code_points_consumed_as_character_reference();
+                        self.reconsume_in(self.return_state());
+                    }
+                }
+            }
+
+            State::HexadecimalCharacterReference => {
+                match self.consume() {
+                    Some(c) if c.is_ascii_hexdigit() => {
+                        self.add_to_character_reference_code(16, c);
+                    }
+                    Some(';') => {
+                        self.current_state = State::NumericCharacterReferenceEnd;
+                    }
+                    _ => {
+                        self.emit_error(ParseError::MissingSemicolonAfterCharacterReference);
+                        self.reconsume_in(State::NumericCharacterReferenceEnd);
+                    }
+                }
+            }
+
+            State::DecimalCharacterReference => {
+                match self.consume() {
+                    Some(c) if c.is_ascii_digit() => {
+                        self.add_to_character_reference_code(10, c);
+                    }
+                    Some(';') => {
+                        self.current_state = State::NumericCharacterReferenceEnd;
+                    }
+                    _ => {
+                        self.emit_error(ParseError::MissingSemicolonAfterCharacterReference);
+                        self.reconsume_in(State::NumericCharacterReferenceEnd);
+                    }
+                }
+            }
+
+            State::NumericCharacterReferenceEnd => {
+                // this state does not consume anything, if we got here by reconsuming then the
+                // return state gets to reconsume that character.
+                let code = self.character_reference_code;
+                let replacement = match code {
+                    0x00 => {
+                        self.emit_error(ParseError::NullCharacterReference);
+                        '\u{FFFD}'
+                    }
+                    _ if code > 0x10FFFF => {
+                        self.emit_error(ParseError::CharacterReferenceOutsideUnicodeRange);
+                        '\u{FFFD}'
+                    }
+                    0xD800..=0xDFFF => {
+                        self.emit_error(ParseError::SurrogateCharacterReference);
+                        '\u{FFFD}'
+                    }
+                    _ => {
+                        if is_noncharacter(code) {
+                            self.emit_error(ParseError::NoncharacterCharacterReference);
+                        }
+                        if code == 0x0D || (is_control(code) && !is_ascii_whitespace(code)) {
+                            self.emit_error(ParseError::ControlCharacterReference);
+                        }
+                        windows_1252_replacement(code)
+                            .or_else(|| char::from_u32(code))
+                            .unwrap_or('\u{FFFD}')
+                    }
+                };
+
+                self.temporary_buffer.clear();
+                self.emit_character_reference(replacement.encode_utf8(&mut [0; 4]));
+                self.current_state = self.return_state();
+            }
+
+            State::CDataSection => {
+                match self.consume() {
+                    Some(']') => {
+                        self.token_start = self.char_start;
+                        self.current_state = State::CDataSectionBracket;
+                    }
+                    Some(c) => {
+                        // NULL characters are left for the tree builder to deal with.
+                        self.emit_char(c);
+                    }
+                    None => {
+                        self.emit_error(ParseError::EofInCData);
+                        self.emit_eof();
+                    }
+                }
+            }
+
+            State::CDataSectionBracket => {
+                match self.consume() {
+                    Some(']') => {
+                        self.current_state = State::CDataSectionEnd;
+                    }
+                    _ => {
+                        self.emit_chars_from("]", self.token_start);
+                        self.reconsume_in(State::CDataSection);
+                    }
+                }
+            }
+
+            State::CDataSectionEnd => {
+                match self.consume() {
+                    Some(']') => {
+                        // only the first of the brackets is text, the last two might still end
+                        // the section.
+                        self.emit_chars_from("]", self.token_start);
+                        self.token_start = self.token_start.advance(']');
+                    }
+                    Some('>') => {
+                        self.current_state = State::Data;
+                    }
+                    _ => {
+                        self.emit_chars_from("]]", self.token_start);
+                        self.reconsume_in(State::CDataSection);
+                    }
+                }
+            }
+        }
+
+        true
+    }
+}
+
+impl Iterator for Tokenizer {
+    type Item = TokenType;
+
+    fn next(&mut self) -> Option<TokenType> {
+        self.next_spanned().map(|(token, _)| token)
+    }
+}
+
+// Tokenizes a whole input which outlives the tokens, so that they can borrow from it instead of
+// copying every tag name, attribute and piece of text into a string of its own. That saves an
+// allocation per token, not the copy of the input: the Tokenizer inside still reads from a copy
+// of source of its own, made once when this is created. It derefs to the Tokenizer for reading its
+// state, but only passes on the calls which leave the input alone: feeding it more would break
+// the offsets into source. Text inserted at an insertion point is fine, it is not in source so
+// the tokens from there on are owned.
+pub struct BorrowedTokenizer<'a> {
+    source: &'a str,
+    tokenizer: Tokenizer,
+    // the offset up to which the tokenizer's input is still the same as source, which is the
+    // first place anything was inserted at.
+    borrowed_up_to: usize,
+}
+
+impl<'a> BorrowedTokenizer<'a> {
+    pub fn new(source: &'a str) -> Self {
+        BorrowedTokenizer::with_options(source, TokenizerOptions::default())
+    }
+
+    pub fn with_limits(source: &'a str, limits: Limits) -> Self {
+        BorrowedTokenizer::with_options(source, TokenizerOptions { limits, ..TokenizerOptions::default() })
+    }
+
+    pub fn with_options(source: &'a str, options: TokenizerOptions) -> Self {
+        BorrowedTokenizer {
+            source,
+            tokenizer: Tokenizer::with_options(source, options),
+            borrowed_up_to: usize::MAX,
+        }
+    }
+
+    pub fn next_spanned(&mut self) -> Option<(Token<'a>, Span)> {
+        let (token, span) = self.tokenizer.next_buffered()?;
+        // the tokenizer was given all of source at once and never dropped any of it, so offsets
+        // into its input are offsets into source, up to where text got inserted.
+        if span.end.offset <= self.borrowed_up_to {
+            return Some((token.into_token(self.source, 0), span));
+        }
+        let (input, input_start) = self.tokenizer.input.buffer();
+        Some((token.into_token(input, input_start).into_static(), span))
+    }
+
+    pub fn push_insertion_point(&mut self) {
+        self.tokenizer.push_insertion_point();
+    }
+
+    pub fn pop_insertion_point(&mut self) {
+        self.tokenizer.pop_insertion_point();
+    }
+
+    // see Tokenizer::insert.
+    pub fn insert(&mut self, text: &str) {
+        if let Some(offset) = self.tokenizer.input.insertion_point() {
+            self.borrowed_up_to = self.borrowed_up_to.min(offset);
+        }
+        self.tokenizer.insert(text);
+    }
+
+    pub fn set_state(&mut self, state: State) {
+        self.tokenizer.set_state(state);
+    }
+
+    pub fn set_last_start_tag_name(&mut self, name: Option<&str>) {
+        self.tokenizer.set_last_start_tag_name(name);
+    }
+
+    pub fn set_in_foreign_content(&mut self, in_foreign_content: bool) {
+        self.tokenizer.set_in_foreign_content(in_foreign_content);
+    }
+
+    pub fn errors(&self) -> &[(ParseError, Span)] {
+        self.tokenizer.errors()
+    }
+
+    pub fn take_errors(&mut self) -> Vec<(ParseError, Span)> {
+        self.tokenizer.take_errors()
+    }
+}
+
+impl<'a> Iterator for BorrowedTokenizer<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        self.next_spanned().map(|(token, _)| token)
+    }
+}
+
+impl Deref for BorrowedTokenizer<'_> {
+    type Target = Tokenizer;
+
+    fn deref(&self) -> &Tokenizer {
+        &self.tokenizer
+    }
+}
+
+// the elements which can not have content, so their start tag does not leave anything open.
+const VOID_ELEMENTS: &[&str] =
+    &["area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track", "wbr"];
+
+fn is_ascii_whitespace(code: u32) -> bool {
+    matches!(code, 0x09 | 0x0A | 0x0C | 0x0D | 0x20)
+}
+
+// numeric character references in the 0x80..=0x9F range are read as windows-1252, which is what
+// legacy pages meant by them.
+fn windows_1252_replacement(code: u32) -> Option<char> {
+    let replacement = match code {
+        0x80 => '\u{20AC}',
+        0x82 => '\u{201A}',
+        0x83 => '\u{0192}',
+        0x84 => '\u{201E}',
+        0x85 => '\u{2026}',
+        0x86 => '\u{2020}',
+        0x87 => '\u{2021}',
+        0x88 => '\u{02C6}',
+        0x89 => '\u{2030}',
+        0x8A => '\u{0160}',
+        0x8B => '\u{2039}',
+        0x8C => '\u{0152}',
+        0x8E => '\u{017D}',
+        0x91 => '\u{2018}',
+        0x92 => '\u{2019}',
+        0x93 => '\u{201C}',
+        0x94 => '\u{201D}',
+        0x95 => '\u{2022}',
+        0x96 => '\u{2013}',
+        0x97 => '\u{2014}',
+        0x98 => '\u{02DC}',
+        0x99 => '\u{2122}',
+        0x9A => '\u{0161}',
+        0x9B => '\u{203A}',
+        0x9C => '\u{0153}',
+        0x9E => '\u{017E}',
+        0x9F => '\u{0178}',
+        _ => return None,
+    };
+    Some(replacement)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::*;
+
+    fn owned(tokens: Vec<Token<'_>>) -> Vec<TokenType> {
+        tokens.into_iter().map(Token::into_owned).collect()
+    }
+
+    // the tokens of input with the tokenizer switched to script data after the first start tag.
+    fn script_tokens(input: &str) -> Vec<TokenType> {
+        let mut tokenizer = Tokenizer::new(input);
+        let mut tokens = vec![tokenizer.next().unwrap()];
+        tokenizer.set_state(State::ScriptData);
+        tokens.extend(tokenizer);
+        tokens
+    }
+
+    // the text, comment or attribute values of a borrowed token, None for any other token.
+    fn borrowed_parts(token: Token<'_>) -> Option<Vec<(String, bool)>> {
+        let part = |text: Cow<'_, str>| (text.to_string(), matches!(text, Cow::Borrowed(_)));
+        match token {
+            Token::Character { data } | Token::Comment { data } => Some(vec![part(data)]),
+            Token::StartTag { attributes, .. } => {
+                Some(attributes.into_iter().map(|(_, value)| part(value)).collect())
+            }
+            _ => None,
+        }
+    }
+
+    // Text, comments and attribute values are slices of the source, until something turns them
+    // into text the source does not have: a character reference, a CR turned into a LF or a NULL
+    // turned into U+FFFD. A "&" which is not a character reference changes nothing.
+    #[test]
+    fn borrowed_tokens_borrow_from_the_source() {
+        let parts = |source: &str| -> Vec<(String, bool)> {
+            BorrowedTokenizer::new(source).filter_map(borrowed_parts).flatten().collect()
+        };
+        let borrowed = |text: &str| (text.to_string(), true);
+        let owned = |text: &str| (text.to_string(), false);
+
+        assert_eq!(
+            parts("<p title=x class='a b' id=\"c\">hello world<!-- comment --></p>"),
+            [borrowed("x"), borrowed("a b"), borrowed("c"), borrowed("hello world"), borrowed(" comment ")]
+        );
+        assert_eq!(parts("a&b"), [borrowed("a&b")]);
+        assert_eq!(parts("a&amp;b"), [owned("a&b")]);
+        assert_eq!(parts("<p title='a&amp;b'>"), [owned("a&b")]);
+        assert_eq!(parts("a\r\nb"), [owned("a\nb")]);
+        assert_eq!(parts("<!--a\0b-->"), [owned("a\u{FFFD}b")]);
+        // only the tokens with something changed in them are owned.
+        assert_eq!(parts("<p title=x class=&lt;>a"), [borrowed("x"), owned("<"), borrowed("a")]);
+    }
+
+    #[test]
+    fn options_are_the_same_as_the_setters() {
+        let input = "  a{{ b }}<?c?><p title=abcdef>";
+        let options = TokenizerOptions {
+            split_leading_whitespace: true,
+            processing_instructions: true,
+            template_delimiters: vec![TemplateDelimiter::new("{{", "}}")],
+            limits: Limits {
+                attribute_value_length: Some(Limit::new(3, LimitBehavior::Truncate)),
+                ..Limits::default()
+            },
+        };
+        let mut tokenizer = Tokenizer::streaming();
+        tokenizer.set_split_leading_whitespace(true);
+        tokenizer.set_processing_instructions(true);
+        tokenizer.set_template_delimiters(options.template_delimiters.clone());
+        tokenizer.set_limits(options.limits);
+        tokenizer.feed(input);
+        tokenizer.end();
+        let expected: Vec<TokenType> = tokenizer.collect();
+        assert_eq!(expected.len(), 6);
+
+        assert_eq!(Tokenizer::with_options(input, options.clone()).collect::<Vec<_>>(), expected);
+        assert_eq!(owned(BorrowedTokenizer::with_options(input, options).collect()), expected);
+    }
+
+    // runs a script the way a tree builder would: an insertion point after the </script>, the
+    // writes, then the rest of the input once the insertion point is gone.
+    #[test]
+    fn borrowed_tokenizer_reads_inserted_text() {
+        let mut tokenizer = BorrowedTokenizer::new("<script>a</script><p>hello world</p>");
+        let mut tokens = vec![tokenizer.next().unwrap()];
+        tokenizer.set_state(State::ScriptData);
+        tokens.extend(tokenizer.by_ref().take(2));
+        tokenizer.push_insertion_point();
+        tokenizer.insert("<b>inserted text</b>");
+        tokens.extend(tokenizer.by_ref().take(3));
+        tokenizer.pop_insertion_point();
+        tokens.extend(tokenizer);
+
+        let expected = script_tokens("<script>a</script><b>inserted text</b><p>hello world</p>");
+        assert_eq!(owned(tokens), expected);
+    }
+
+    fn with_processing_instructions(input: &str) -> Vec<TokenType> {
+        let mut tokenizer = Tokenizer::streaming();
+        tokenizer.set_processing_instructions(true);
+        tokenizer.feed(input);
+        tokenizer.end();
+        tokenizer.collect()
+    }
+
+    fn comment(data: &str) -> TokenType {
+        TokenType::Comment { data: data.to_string() }
+    }
+
+    fn text(data: &str) -> TokenType {
+        TokenType::Character { data: data.to_string() }
+    }
+
+    fn processing_instruction(target: &str, data: &str) -> TokenType {
+        TokenType::ProcessingInstruction {
+            target: target.to_string(),
+            data: data.to_string(),
+        }
+    }
+
+    #[test]
+    fn empty_processing_instruction() {
+        assert_eq!(
+            with_processing_instructions("a<?>b"),
+            [text("a"), processing_instruction("", ""), text("b"), TokenType::EOF]
+        );
+    }
+
+    #[test]
+    fn unterminated_processing_instruction_is_a_bogus_comment() {
+        let input = "<?xml version=1.0><p>text\0</p>";
+        let tokens = with_processing_instructions(input);
+        assert_eq!(tokens, Tokenizer::new(input).collect::<Vec<_>>());
+        assert_eq!(tokens[0], comment("?xml version=1.0"));
+
+        let mut tokenizer = Tokenizer::streaming();
+        tokenizer.set_processing_instructions(true);
+        tokenizer.feed(input);
+        tokenizer.end();
+        tokenizer.by_ref().for_each(drop);
+        let errors: Vec<ParseError> = tokenizer.errors().iter().map(|(error, _)| *error).collect();
+        assert_eq!(
+            errors,
+            [ParseError::UnexpectedQuestionMarkInsteadOfTagName, ParseError::UnexpectedNullCharacter]
+        );
+
+        assert_eq!(with_processing_instructions("<?"), [comment("?"), TokenType::EOF]);
+        assert_eq!(with_processing_instructions("<?a?"), [comment("?a?"), TokenType::EOF]);
+    }
+
+    #[test]
+    fn processing_instructions() {
+        assert_eq!(
+            with_processing_instructions("<?xml version=\"1.0\"?><?php echo 1 > 0; ?>"),
+            [
+                processing_instruction("xml", "version=\"1.0\""),
+                processing_instruction("php", "echo 1 > 0; "),
+                TokenType::EOF
+            ]
+        );
+        assert_eq!(
+            with_processing_instructions("<?a?b?>"),
+            [processing_instruction("a", "?b"), TokenType::EOF]
+        );
+    }
+
+    #[test]
+    fn encoding_can_only_be_set_before_decoding() {
+        let mut tokenizer = Tokenizer::streaming();
+        assert!(tokenizer.set_encoding(Encoding::Windows1252));
+        assert!(tokenizer.set_encoding(Encoding::ShiftJis));
+        tokenizer.feed_bytes(b"");
+        assert!(tokenizer.set_encoding(Encoding::ShiftJis));
+        tokenizer.feed_bytes(b"\x82\xA0");
+        assert!(!tokenizer.set_encoding(Encoding::Windows1252));
+        assert_eq!(tokenizer.encoding(), Some(Encoding::ShiftJis));
+        tokenizer.end();
+        assert_eq!(tokenizer.collect::<Vec<_>>(), [text("\u{3042}"), TokenType::EOF]);
+
+        // without set_encoding feed_bytes decodes UTF-8, which is then too late to change.
+        let mut tokenizer = Tokenizer::streaming();
+        tokenizer.feed_bytes(b"a");
+        assert!(!tokenizer.set_encoding(Encoding::ShiftJis));
+        assert_eq!(tokenizer.encoding(), Some(Encoding::Utf8));
+
+        // so are bytes held back because they might be a byte order mark.
+        let mut tokenizer = Tokenizer::streaming();
+        tokenizer.feed_bytes(b"\xEF");
+        assert!(!tokenizer.set_encoding(Encoding::ShiftJis));
+    }
+
+    type Tokenized = (Vec<(TokenType, Span)>, Vec<(ParseError, Span)>);
+
+    // the tokens with their spans and the errors, taking what there is after every chunk.
+    fn tokenize_chunks<C>(chunks: &[C], feed: impl Fn(&mut Tokenizer, &C)) -> Tokenized {
+        let mut tokenizer = Tokenizer::streaming();
+        let mut tokens = Vec::new();
+        for chunk in chunks {
+            feed(&mut tokenizer, chunk);
+            tokens.extend(std::iter::from_fn(|| tokenizer.next_spanned()));
+        }
+        tokenizer.end();
+        tokens.extend(std::iter::from_fn(|| tokenizer.next_spanned()));
+        (tokens, tokenizer.take_errors())
+    }
+
+    const CHUNKED_INPUTS: &[&str] = &[
+        "a\r\nb\rc\r\r\nd\r",
+        "<p title='x\r\ny\r'>\u{E9}\u{65E5}\r\n\u{1F600}</p\r\n>",
+        "&amp;&notin;&notit;&#x1F600;&#13;\r\n&",
+        "<!--\r\n-->\r<!DOCTYPE html\r\nPUBLIC \"a\r\nb\">",
+        "<svg><![CDATA[a]]b]]></svg>",
+        "\u{80}\u{FDD0}\r\u{FFFF}\r\n\u{10FFFF}\u{0}",
+    ];
+
+    // Tokenizing has to stop wherever a chunk ends and pick up where it left off, which includes
+    // a CR whose LF is in the next chunk and, with feed_bytes, characters split in between.
+    #[test]
+    fn chunk_boundaries() {
+        let (tokens, _) = tokenize_chunks(&["a\r", "\nb\r", "\r\n"], |tokenizer, chunk| tokenizer.feed(chunk));
+        let tokens: Vec<TokenType> = tokens.into_iter().map(|(token, _)| token).collect();
+        assert_eq!(tokens, [text("a\nb\n\n"), TokenType::EOF]);
+
+        for input in CHUNKED_INPUTS {
+            let expected = tokenize_chunks(&[*input], |tokenizer, chunk| tokenizer.feed(chunk));
+
+            let chars: Vec<String> = input.chars().map(String::from).collect();
+            assert_eq!(tokenize_chunks(&chars, |tokenizer, chunk| tokenizer.feed(chunk)), expected, "{:?}", input);
+            for (split, _) in input.char_indices() {
+                let chunks = [&input[..split], &input[split..]];
+                let tokenized = tokenize_chunks(&chunks, |tokenizer, chunk| tokenizer.feed(chunk));
+                assert_eq!(tokenized, expected, "{:?}", chunks);
+            }
+
+            let bytes: Vec<u8> = input.bytes().collect();
+            assert_eq!(
+                tokenize_chunks(&bytes, |tokenizer, byte| tokenizer.feed_bytes(std::slice::from_ref(byte))),
+                expected,
+                "{:?}",
+                input
+            );
+        }
+    }
+
+    fn split_leading_whitespace(chunks: &[&str]) -> Vec<TokenType> {
+        let mut tokenizer = Tokenizer::streaming();
+        tokenizer.set_split_leading_whitespace(true);
+        let mut tokens = Vec::new();
+        for chunk in chunks {
+            tokenizer.feed(chunk);
+            tokens.extend(tokenizer.by_ref());
+        }
+        tokenizer.end();
+        tokens.extend(tokenizer);
+        tokens
+    }
+
+    #[test]
+    fn leading_whitespace_is_split_off() {
+        let start_tag = |name: &str| TokenType::StartTag {
+            tag_name: name.into(),
+            self_closing: false,
+            attributes: Vec::new(),
+        };
+        let input = " \n\t\u{C}hello world\n<p>\r\n\r\n</p>&#32;\t&amp;x &lt;";
+        let expected = [
+            text(" \n\t\u{C}"),
+            text("hello world\n"),
+            start_tag("p"),
+            text("\n\n"),
+            TokenType::EndTag {
+                tag_name: "p".into(),
+                self_closing: false,
+                attributes: Vec::new(),
+            },
+            // whitespace from a character reference counts too, the tree builder only sees the
+            // character.
+            text(" \t"),
+            text("&x <"),
+            TokenType::EOF,
+        ];
+        assert_eq!(split_leading_whitespace(&[input]), expected);
+        // without it a run of text is one token, whitespace or not.
+        assert_eq!(Tokenizer::new(" a b ").collect::<Vec<_>>(), [text(" a b "), TokenType::EOF]);
+
+        // the same when the whitespace is cut off by the end of a chunk.
+        let chars: Vec<String> = input.chars().map(String::from).collect();
+        let chunks: Vec<&str> = chars.iter().map(String::as_str).collect();
+        assert_eq!(split_leading_whitespace(&chunks), expected);
+        assert_eq!(split_leading_whitespace(&[" ", " a", " b"]), [text("  "), text("a b"), TokenType::EOF]);
+        assert_eq!(split_leading_whitespace(&["  "]), [text("  "), TokenType::EOF]);
+    }
+
+    // the tokens with their spans up to and with the first that matches, or all there are for now.
+    fn next_until(tokenizer: &mut Tokenizer, until: impl Fn(&TokenType) -> bool) -> Vec<(TokenType, Span)> {
+        let mut tokens = Vec::new();
+        while let Some((token, span)) = tokenizer.next_spanned() {
+            if let TokenType::StartTag { tag_name, .. } = &token
+                && tag_name == "script"
+            {
+                tokenizer.set_state(State::ScriptData);
+            }
+            let found = until(&token);
+            tokens.push((token, span));
+            if found {
+                break;
+            }
+        }
+        tokens
+    }
+
+    fn is_script_end_tag(token: &TokenType) -> bool {
+        matches!(token, TokenType::EndTag { tag_name, .. } if tag_name == "script")
+    }
+
+    // A script writing a script which writes too: the inner one inserts at its own insertion
+    // point, right after its </script>, the outer one afterwards at its insertion point, which
+    // has moved along behind the text of the inner one.
+    #[test]
+    fn nested_insertion_points() {
+        let mut tokenizer = Tokenizer::new("<script>outer</script>after");
+        let mut tokens = next_until(&mut tokenizer, is_script_end_tag);
+        tokenizer.push_insertion_point();
+        tokenizer.insert("<script>inner");
+        tokenizer.insert("</script>");
+        tokens.extend(next_until(&mut tokenizer, is_script_end_tag));
+
+        tokenizer.push_insertion_point();
+        tokenizer.insert("<b>B</b>");
+        // the tokenizer stops at the inner insertion point, which keeps the text open.
+        tokens.extend(next_until(&mut tokenizer, |_| false));
+        assert!(tokenizer.next().is_none());
+        tokenizer.pop_insertion_point();
+        tokens.extend(next_until(&mut tokenizer, |_| false));
+
+        tokenizer.insert("C");
+        // nothing left to insert at once the outer insertion point is gone as well.
+        tokenizer.pop_insertion_point();
+        tokenizer.insert("lost");
+        tokens.extend(next_until(&mut tokenizer, |_| false));
+
+        let mut expected = Tokenizer::new("<script>outer</script><script>inner</script><b>B</b>Cafter");
+        assert_eq!(tokens, next_until(&mut expected, |_| false));
+    }
+}
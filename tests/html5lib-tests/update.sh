@@ -0,0 +1,22 @@
+#!/bin/sh
+# Replaces the vendored upstream tests with the ones on html5lib-tests' master branch:
+#
+#     tests/html5lib-tests/update.sh
+#
+# then run `cargo test --test html5lib` and take the tests it says pass off SKIPPED in
+# tests/html5lib/main.rs and tests/html5lib/encoding.rs.
+set -eu
+
+cd "$(dirname "$0")"
+archive=$(mktemp -d)
+trap 'rm -rf "$archive"' EXIT
+
+curl -sSfL https://github.com/html5lib/html5lib-tests/archive/refs/heads/master.tar.gz \
+    | tar -xz -C "$archive" --strip-components=1
+
+rm -rf tokenizer encoding
+mkdir tokenizer encoding
+cp "$archive"/tokenizer/*.test "$archive"/tokenizer/README.md tokenizer/
+# chardet/ is about guessing the encoding from the bytes, which the crate does not do.
+cp "$archive"/encoding/*.dat encoding/
+cp "$archive"/LICENSE "$archive"/AUTHORS.rst .
@@ -0,0 +1,102 @@
+// Runs the encoding tests in the html5lib-tests format, the upstream ones from
+// tests/html5lib-tests/encoding and the crate's own from local/encoding. Each test is a #data
+// section with the start of a page and an #encoding section with the label of the encoding
+// sniffing should settle on, without a transport charset.
+use std::fs;
+use std::path::Path;
+
+use html_parser::Encoding;
+
+// The upstream tests which are known to fail, by file and number (counting from 1), with why. A
+// skipped test which passes fails the run.
+static SKIPPED: &[(&str, usize, &str)] = &[
+    ("encoding/tests1.dat", 48, LATE_META),
+    ("encoding/tests1.dat", 49, LATE_META),
+    ("encoding/tests1.dat", 50, LATE_META),
+    ("encoding/tests1.dat", 51, LATE_META),
+    ("encoding/tests1.dat", 52, LATE_META),
+    ("encoding/tests1.dat", 53, LATE_META),
+    ("encoding/tests1.dat", 54, LATE_META),
+    ("encoding/tests1.dat", 55, WRITTEN_META),
+];
+
+const LATE_META: &str = "the <meta> is past the 1024 bytes of the prescan, only a tree builder finds it";
+const WRITTEN_META: &str = "the <meta> is written by a script";
+
+#[test]
+fn html5lib_encoding_tests() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/html5lib-tests");
+    let mut failures = Vec::new();
+    for directory in ["encoding", "local/encoding"] {
+        let mut paths: Vec<_> = fs::read_dir(root.join(directory))
+            .unwrap_or_else(|error| panic!("tests/html5lib-tests/{}: {}", directory, error))
+            .map(|entry| entry.expect("unreadable directory entry").path())
+            .filter(|path| path.extension().is_some_and(|extension| extension == "dat"))
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let file_name = format!("{}/{}", directory, path.file_name().unwrap().to_string_lossy());
+            // the files are bytes, some of the pages in them are not UTF-8.
+            let bytes = fs::read(&path).expect("unreadable test file");
+
+            let (mut passed, mut failed, mut skipped) = (0, 0, 0);
+            for (index, (data, label)) in read_tests(&bytes).into_iter().enumerate() {
+                let known_failure = SKIPPED
+                    .iter()
+                    .any(|&(file, number, _)| file == file_name && number == index + 1);
+                let expected = Encoding::for_label(&label);
+                let (encoding, _) = Encoding::sniff(&data, None);
+                match (expected == Some(encoding), known_failure) {
+                    (true, false) => passed += 1,
+                    (false, true) => skipped += 1,
+                    (true, true) => {
+                        failed += 1;
+                        failures.push(format!("{} / {}: passes, take it off SKIPPED", file_name, index + 1));
+                    }
+                    (false, false) => {
+                        failed += 1;
+                        failures.push(format!(
+                            "{} / {} {:?}: expected {}, got {}",
+                            file_name,
+                            index + 1,
+                            String::from_utf8_lossy(&data),
+                            label,
+                            encoding
+                        ));
+                    }
+                }
+            }
+            println!("{}: {} passed, {} failed, {} skipped", file_name, passed, failed, skipped);
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} html5lib encoding tests failed:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+// the data and the expected label of every test in a file. The data runs up to the line before
+// #encoding, without the newline that ends it.
+fn read_tests(bytes: &[u8]) -> Vec<(Vec<u8>, String)> {
+    let mut tests = Vec::new();
+    let mut lines = bytes.split(|&b| b == b'\n').peekable();
+    while let Some(line) = lines.next() {
+        if line != b"#data" {
+            continue;
+        }
+        let mut data_lines = Vec::new();
+        while let Some(line) = lines.next_if(|line| *line != b"#encoding") {
+            data_lines.push(line);
+        }
+        let data = data_lines.join(&b'\n');
+        lines.next();
+        if let Some(label) = lines.next() {
+            tests.push((data, String::from_utf8_lossy(label).trim().to_string()));
+        }
+    }
+    tests
+}
@@ -0,0 +1,405 @@
+// Runs the tokenizer tests in the html5lib-tests format, once for every initial state a test
+// lists, and checks that streaming the input gives the same result. tests/html5lib-tests/tokenizer
+// is the upstream suite and local/tokenizer has the crate's own cases. Every file gets a line with
+// how many of its tests passed, failed and were skipped, which shows up with
+// `cargo test --test html5lib -- --nocapture` or when something fails. The encoding sniffing
+// tests are in encoding.rs.
+mod encoding;
+mod json;
+
+use std::fs;
+use std::path::Path;
+
+use html_parser::{DocTypeIdentifier, State, TokenType, Tokenizer};
+use json::Json;
+
+// The upstream tests which are known to fail, by file and description, with why. A skipped test
+// which passes fails the run, so that the list does not outlive the reason for it. Apart from
+// xmlViolation.test, these are only here because the vendored files are from before the spec
+// gave its errors codes. They expect a different number of errors, the tokens all match, and
+// they go once tests/html5lib-tests/update.sh has brought in the current files.
+static SKIPPED: &[(&str, &str, &str)] = &[
+    ("tokenizer/xmlViolation.test", "Non-XML character", XML_VIOLATION),
+    ("tokenizer/xmlViolation.test", "Non-XML space", XML_VIOLATION),
+    ("tokenizer/xmlViolation.test", "Double hyphen in comment", XML_VIOLATION),
+    ("tokenizer/pendingSpecChanges.test", "<!---- >", DASHES),
+    ("tokenizer/test1.test", "Comment, Central dash no space", DASHES),
+    ("tokenizer/test1.test", "Comment, two central dashes", DASHES),
+    ("tokenizer/test3.test", "<!----\\u0009", DASHES),
+    ("tokenizer/test3.test", "<!----\\u000A", DASHES),
+    ("tokenizer/test3.test", "<!----\\u000B", DASHES),
+    ("tokenizer/test3.test", "<!----\\u000C", DASHES),
+    ("tokenizer/test3.test", "<!---- ", DASHES),
+    ("tokenizer/test3.test", "<!---- -", DASHES),
+    ("tokenizer/test3.test", "<!---- --", DASHES),
+    ("tokenizer/test3.test", "<!---- -->", DASHES),
+    ("tokenizer/test3.test", "<!----  -->", DASHES),
+    ("tokenizer/test3.test", "<!---- a-->", DASHES),
+    ("tokenizer/test3.test", "<!----!", DASHES),
+    ("tokenizer/test3.test", "<!----!a", DASHES),
+    ("tokenizer/test3.test", "<!----!a-", DASHES),
+    ("tokenizer/test3.test", "<!----!a--", DASHES),
+    ("tokenizer/test3.test", "<!----!a-->", DASHES),
+    ("tokenizer/test3.test", "<!----!-", DASHES),
+    ("tokenizer/test3.test", "<!----!--", DASHES),
+    ("tokenizer/test3.test", "<!----!-->", DASHES),
+    ("tokenizer/test3.test", "<!----\"", DASHES),
+    ("tokenizer/test3.test", "<!----&", DASHES),
+    ("tokenizer/test3.test", "<!----'", DASHES),
+    ("tokenizer/test3.test", "<!----,", DASHES),
+    ("tokenizer/test3.test", "<!-----", DASHES),
+    ("tokenizer/test3.test", "<!----.", DASHES),
+    ("tokenizer/test3.test", "<!----/", DASHES),
+    ("tokenizer/test3.test", "<!----0", DASHES),
+    ("tokenizer/test3.test", "<!----1", DASHES),
+    ("tokenizer/test3.test", "<!----9", DASHES),
+    ("tokenizer/test3.test", "<!----<", DASHES),
+    ("tokenizer/test3.test", "<!----=", DASHES),
+    ("tokenizer/test3.test", "<!----?", DASHES),
+    ("tokenizer/test3.test", "<!----@", DASHES),
+    ("tokenizer/test3.test", "<!----A", DASHES),
+    ("tokenizer/test3.test", "<!----B", DASHES),
+    ("tokenizer/test3.test", "<!----Y", DASHES),
+    ("tokenizer/test3.test", "<!----Z", DASHES),
+    ("tokenizer/test3.test", "<!----`", DASHES),
+    ("tokenizer/test3.test", "<!----a", DASHES),
+    ("tokenizer/test3.test", "<!----b", DASHES),
+    ("tokenizer/test3.test", "<!----y", DASHES),
+    ("tokenizer/test3.test", "<!----z", DASHES),
+    ("tokenizer/test3.test", "<!----{", DASHES),
+    ("tokenizer/test3.test", "<!----\\uDBC0\\uDC00", DASHES),
+    ("tokenizer/domjs.test", "--!NUL in comment ", NULL),
+    ("tokenizer/test3.test", "<!\\u0000", NULL),
+    ("tokenizer/test3.test", "<!DOCTYPE a \\u0000", NULL),
+    ("tokenizer/test3.test", "<!DOCTYPE a PUBLIC\\u0000", NULL),
+    ("tokenizer/test3.test", "<!DOCTYPE a PUBLIC''\\u0000", NULL),
+    ("tokenizer/test3.test", "<!DOCTYPE a SYSTEM\\u0000", NULL),
+    ("tokenizer/test3.test", "<!DOCTYPE a SYSTEM''\\u0000", NULL),
+    ("tokenizer/test3.test", "<!DOCTYPE a a\\u0000", NULL),
+    ("tokenizer/test3.test", "<!DOCTYPEa \\u0000", NULL),
+    ("tokenizer/test3.test", "<!DOCTYPEa PUBLIC\\u0000", NULL),
+    ("tokenizer/test3.test", "<!DOCTYPEa PUBLIC''\\u0000", NULL),
+    ("tokenizer/test3.test", "<!DOCTYPEa SYSTEM\\u0000", NULL),
+    ("tokenizer/test3.test", "<!DOCTYPEa SYSTEM''\\u0000", NULL),
+    ("tokenizer/test3.test", "<!DOCTYPEa a\\u0000", NULL),
+    ("tokenizer/test3.test", "</\\u0000", NULL),
+    ("tokenizer/test3.test", "<?\\u0000", NULL),
+    ("tokenizer/test4.test", "U+0000 in lookahead region", NULL),
+    ("tokenizer/test2.test", "DOCTYPE without name", DOCTYPE),
+    ("tokenizer/test3.test", "<!DOCTYPE>", DOCTYPE),
+];
+
+const XML_VIOLATION: &str = "expects the output of a mode which coerces the tokens into XML";
+const DASHES: &str = "\"--\" inside a comment stopped being a parse error";
+const NULL: &str = "a NULL in a bogus comment or doctype is reported as unexpected-null-character";
+const DOCTYPE: &str = "\"<!DOCTYPE>\" is only missing-doctype-name";
+
+#[test]
+fn html5lib_tokenizer_tests() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/html5lib-tests");
+    let mut failures = Vec::new();
+    for directory in ["tokenizer", "local/tokenizer"] {
+        let mut paths: Vec<_> = fs::read_dir(root.join(directory))
+            .unwrap_or_else(|error| panic!("tests/html5lib-tests/{}: {}", directory, error))
+            .map(|entry| entry.expect("unreadable directory entry").path())
+            .filter(|path| path.extension().is_some_and(|extension| extension == "test"))
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let file_name = format!("{}/{}", directory, path.file_name().unwrap().to_string_lossy());
+            let text = fs::read_to_string(&path).expect("unreadable test file");
+            let file = json::parse(&text).unwrap_or_else(|error| panic!("{}: {}", file_name, error));
+            // xmlViolation.test keeps its tests under another name.
+            let tests = file
+                .get("tests")
+                .or_else(|| file.get("xmlViolationTests"))
+                .and_then(Json::as_array)
+                .unwrap_or(&[]);
+
+            let (mut passed, mut failed, mut skipped) = (0, 0, 0);
+            for test in tests {
+                let description = test.get("description").and_then(Json::as_str).unwrap_or("");
+                let known_failure = SKIPPED
+                    .iter()
+                    .any(|&(file, skipped, _)| file == file_name && skipped == description);
+                let Some(case) = Case::read(test) else {
+                    skipped += 1;
+                    continue;
+                };
+
+                for state_name in &case.initial_states {
+                    match (case.run(state_name), known_failure) {
+                        (Ok(()), false) => passed += 1,
+                        (Err(_), true) => skipped += 1,
+                        (Ok(()), true) => {
+                            failed += 1;
+                            failures.push(format!("{} / {} / {}: passes, take it off SKIPPED", file_name, description, state_name));
+                        }
+                        (Err(message), false) => {
+                            failed += 1;
+                            failures.push(format!("{} / {} / {}: {}", file_name, description, state_name, message));
+                        }
+                    }
+                }
+            }
+            println!("{}: {} passed, {} failed, {} skipped", file_name, passed, failed, skipped);
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} html5lib tokenizer tests failed:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+struct Case {
+    input: String,
+    output: Vec<Json>,
+    errors: ExpectedErrors,
+    initial_states: Vec<String>,
+    last_start_tag: Option<String>,
+}
+
+#[derive(Debug)]
+enum ExpectedErrors {
+    Codes { codes: Vec<String>, ignore_order: bool },
+    // only how many, for the files from before there were codes.
+    Count(usize),
+}
+
+impl Case {
+    // None for the tests which can not be run, which are the ones whose input has a lone
+    // surrogate in it, a str can not hold one.
+    fn read(test: &Json) -> Option<Case> {
+        let double_escaped = test.get("doubleEscaped") == Some(&Json::Bool(true));
+        let unescape = |json: &Json| -> Option<Json> {
+            if double_escaped {
+                unescape_json(json)
+            } else {
+                Some(json.clone())
+            }
+        };
+
+        let input = unescape(test.get("input")?)?.as_str()?.to_string();
+        let mut output = unescape(test.get("output")?)?.as_array()?.to_vec();
+        // The older upstream files put a "ParseError" in the output for every error instead of
+        // listing the codes, which they predate.
+        let parse_errors = output.iter().filter(|token| token.as_str() == Some("ParseError")).count();
+        let errors = match test.get("errors").and_then(Json::as_array) {
+            Some(errors) => {
+                let mut codes: Vec<String> = errors
+                    .iter()
+                    .filter_map(|error| error.get("code")?.as_str().map(str::to_string))
+                    .collect();
+                let ignore_order = test.get("ignoreErrorOrder") == Some(&Json::Bool(true));
+                if ignore_order {
+                    codes.sort();
+                }
+                ExpectedErrors::Codes { codes, ignore_order }
+            }
+            None if parse_errors > 0 => {
+                output.retain(|token| token.as_str() != Some("ParseError"));
+                ExpectedErrors::Count(parse_errors)
+            }
+            None => ExpectedErrors::Codes {
+                codes: Vec::new(),
+                ignore_order: false,
+            },
+        };
+        let initial_states = match test.get("initialStates").and_then(Json::as_array) {
+            Some(states) => states.iter().filter_map(|state| state.as_str().map(str::to_string)).collect(),
+            None => vec!["Data state".to_string()],
+        };
+        let last_start_tag = test.get("lastStartTag").and_then(Json::as_str).map(str::to_string);
+
+        Some(Case {
+            input,
+            output,
+            errors,
+            initial_states,
+            last_start_tag,
+        })
+    }
+
+    fn run(&self, state_name: &str) -> Result<(), String> {
+        let state = match state_name {
+            "Data state" => State::Data,
+            "PLAINTEXT state" => State::PlainText,
+            "RCDATA state" => State::RCData,
+            "RAWTEXT state" => State::RawText,
+            "Script data state" => State::ScriptData,
+            "CDATA section state" => State::CDataSection,
+            _ => return Err(format!("unknown initial state {:?}", state_name)),
+        };
+
+        let (output, errors) = self.tokenize(state, false);
+        let expected = normalize_output(self.output.clone());
+        let output = normalize_output(output);
+        if output != expected {
+            return Err(format!("input {:?}\n  expected {:?}\n  got      {:?}", self.input, expected, output));
+        }
+        // the positions are not compared, only the codes and their order.
+        let errors_match = match &self.errors {
+            ExpectedErrors::Codes { codes, ignore_order: true } => {
+                let mut sorted = errors.clone();
+                sorted.sort();
+                sorted == *codes
+            }
+            ExpectedErrors::Codes { codes, .. } => errors == *codes,
+            ExpectedErrors::Count(count) => errors.len() == *count,
+        };
+        if !errors_match {
+            return Err(format!("input {:?}\n  expected errors {:?}\n  got errors      {:?}", self.input, self.errors, errors));
+        }
+
+        // feeding the input one character at a time has to give the same result.
+        let (streamed_output, streamed_errors) = self.tokenize(state, true);
+        if normalize_output(streamed_output) != output || streamed_errors != errors {
+            return Err(format!("input {:?}\n  differs when fed one character at a time", self.input));
+        }
+        Ok(())
+    }
+
+    fn tokenize(&self, state: State, one_character_at_a_time: bool) -> (Vec<Json>, Vec<String>) {
+        let mut tokenizer = Tokenizer::streaming();
+        tokenizer.set_state(state);
+        tokenizer.set_last_start_tag_name(self.last_start_tag.as_deref());
+
+        let mut output = Vec::new();
+        let mut collect = |tokenizer: &mut Tokenizer| {
+            for token in tokenizer.by_ref() {
+                output.extend(token_to_json(token));
+            }
+        };
+        if one_character_at_a_time {
+            for (index, c) in self.input.char_indices() {
+                tokenizer.feed(&self.input[index..index + c.len_utf8()]);
+                collect(&mut tokenizer);
+            }
+        } else {
+            tokenizer.feed(&self.input);
+        }
+        tokenizer.end();
+        collect(&mut tokenizer);
+
+        let errors = tokenizer
+            .take_errors()
+            .into_iter()
+            .map(|(error, _)| error.code().to_string())
+            .collect();
+        (output, errors)
+    }
+}
+
+fn token_to_json(token: TokenType) -> Option<Json> {
+    let string = |string: String| Json::String(string);
+    let identifier = |identifier: DocTypeIdentifier| match identifier {
+        DocTypeIdentifier::Missing => Json::Null,
+        DocTypeIdentifier::Available { id } => Json::String(id),
+    };
+
+    let json = match token {
+        TokenType::DocType {
+            name,
+            public_identifier,
+            system_identifier,
+            force_quirks,
+        } => vec![
+            string("DOCTYPE".to_string()),
+            name.map_or(Json::Null, string),
+            identifier(public_identifier),
+            identifier(system_identifier),
+            Json::Bool(!force_quirks),
+        ],
+        TokenType::StartTag {
+            tag_name,
+            self_closing,
+            attributes,
+        } => {
+            let attributes = attributes.into_iter().map(|attribute| (attribute.name.to_string(), Json::String(attribute.value))).collect();
+            let mut json = vec![string("StartTag".to_string()), string(tag_name.to_string()), Json::Object(attributes)];
+            if self_closing {
+                json.push(Json::Bool(true));
+            }
+            json
+        }
+        TokenType::EndTag { tag_name, .. } => vec![string("EndTag".to_string()), string(tag_name.to_string())],
+        TokenType::Comment { data } => vec![string("Comment".to_string()), string(data)],
+        TokenType::Character { data } => vec![string("Character".to_string()), string(data)],
+        // not part of the format, the tests never turn these on.
+        TokenType::ProcessingInstruction { target, data } => {
+            vec![string("ProcessingInstruction".to_string()), string(target), string(data)]
+        }
+        TokenType::Template { data } => vec![string("Template".to_string()), string(data)],
+        TokenType::EOF => return None,
+    };
+    Some(Json::Array(json))
+}
+
+// The tests may split text into several character tokens and list attributes in any order, so
+// both sides get merged and sorted the same way before comparing.
+fn normalize_output(output: Vec<Json>) -> Vec<Json> {
+    let mut normalized: Vec<Json> = Vec::new();
+    for token in output {
+        let mut token = token;
+        if let Json::Array(items) = &mut token {
+            for item in items.iter_mut() {
+                if let Json::Object(entries) = item {
+                    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+                }
+            }
+        }
+
+        if let (Some(Json::Array(previous)), Json::Array(current)) = (normalized.last_mut(), &token)
+            && let ([Json::String(kind), Json::String(data)], [Json::String(next_kind), Json::String(next_data)]) =
+                (previous.as_mut_slice(), current.as_slice())
+            && kind == "Character"
+            && next_kind == "Character"
+        {
+            data.push_str(next_data);
+            continue;
+        }
+        normalized.push(token);
+    }
+    normalized
+}
+
+// Double escaped tests have their \uXXXX escapes escaped once more, so that lone surrogates can be
+// written down. None if there is one.
+fn unescape_json(json: &Json) -> Option<Json> {
+    Some(match json {
+        Json::String(string) => Json::String(unescape(string)?),
+        Json::Array(items) => Json::Array(items.iter().map(unescape_json).collect::<Option<_>>()?),
+        Json::Object(entries) => Json::Object(
+            entries
+                .iter()
+                .map(|(name, value)| Some((unescape(name)?, unescape_json(value)?)))
+                .collect::<Option<_>>()?,
+        ),
+        other => other.clone(),
+    })
+}
+
+fn unescape(string: &str) -> Option<String> {
+    let mut unescaped = String::new();
+    let mut rest = string;
+    while let Some(index) = rest.find("\\u") {
+        unescaped.push_str(&rest[..index]);
+        let code = u32::from_str_radix(rest.get(index + 2..index + 6)?, 16).ok()?;
+        rest = &rest[index + 6..];
+
+        let code = if (0xD800..0xDC00).contains(&code) && rest.starts_with("\\u") {
+            let low = u32::from_str_radix(rest.get(2..6)?, 16).ok()?;
+            rest = &rest[6..];
+            0x10000 + ((code - 0xD800) << 10) + (low.checked_sub(0xDC00)?)
+        } else {
+            code
+        };
+        unescaped.push(char::from_u32(code)?);
+    }
+    unescaped.push_str(rest);
+    Some(unescaped)
+}